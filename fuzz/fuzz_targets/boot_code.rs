@@ -0,0 +1,11 @@
+#![no_main]
+use aoc::year2020::day08::{fix_program, Program};
+use libfuzzer_sys::fuzz_target;
+
+// Any program that loads must also run to an end, and fixing it must give up cleanly
+fuzz_target!(|data: &[u8]| {
+    if let Ok(mut instructions) = Program::load_instructions_from_str(&String::from_utf8_lossy(data)) {
+        let _ = Program::load(&instructions).run();
+        let _ = fix_program(&mut instructions);
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+use aoc::year2020::day18::evaluate;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let expression = String::from_utf8_lossy(data);
+    let _ = evaluate(&expression, false);
+    let _ = evaluate(&expression, true);
+});
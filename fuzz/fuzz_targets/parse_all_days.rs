@@ -0,0 +1,10 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+// Every day's parser must turn any input into a value or an error, never a panic
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+    for day in aoc::YEARS.iter().flat_map(|year| year.days()) {
+        let _ = day.parse(&input);
+    }
+});
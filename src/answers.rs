@@ -0,0 +1,162 @@
+use crate::solution::Answer;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+// The known correct answers for the puzzle inputs, read from a small subset of TOML:
+//
+// [2020.day1]
+// part1 = 997899
+// part2 = "131248694"
+//
+// Values may be bare numbers or quoted strings, and # starts a comment.
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    answers: HashMap<(String, u32), String>
+}
+
+impl ExpectedAnswers {
+    pub fn load(path: &Path) -> Result<ExpectedAnswers, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+
+        ExpectedAnswers::parse(&text)
+            .map_err(|err| format!("{}:{}", path.display(), err))
+    }
+
+    pub fn parse(text: &str) -> Result<ExpectedAnswers, String> {
+        let mut answers = HashMap::new();
+        let mut section: Option<String> = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let line_number = i + 1;
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.starts_with('[') && line.ends_with(']') {
+                section = Some(line[1..line.len() - 1].trim().to_string());
+                continue;
+            }
+
+            let day = section
+                .clone()
+                .ok_or_else(|| format!("{}: answer outside of a [<year>.dayN] section", line_number))?;
+
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap().trim();
+            let value = parts
+                .next()
+                .ok_or_else(|| format!("{}: expected part1 = <answer>", line_number))?
+                .trim();
+
+            let part = match key {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(format!("{}: unknown key {}", line_number, key))
+            };
+
+            let value = if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+                &value[1..value.len() - 1]
+            } else {
+                value
+            };
+
+            answers.insert((day, part), value.to_string());
+        }
+
+        Ok(ExpectedAnswers {
+            answers
+        })
+    }
+
+    pub fn insert(&mut self, day: &str, part: u32, answer: &str) {
+        self.answers.insert((day.to_string(), part), answer.to_string());
+    }
+
+    pub fn get(&self, day: &str, part: u32) -> Option<&str> {
+        self.answers
+            .get(&(day.to_string(), part))
+            .map(|answer| answer.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong(String),
+    Unknown
+}
+
+pub fn check(expected: Option<&str>, actual: &Answer) -> Verdict {
+    match expected {
+        None => Verdict::Unknown,
+        Some(expected) if expected == actual.to_string() => Verdict::Correct,
+        Some(expected) => Verdict::Wrong(expected.to_string())
+    }
+}
+
+// The line up to a # that isn't inside a quoted value
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => ()
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    #[test]
+    fn test_parse() {
+        let text = r#"
+# Known answers
+[2020.day1]
+part1 = 997899
+part2 = "131248694"  # quoted
+
+[2020.day20]
+part1 = 18449208814679
+
+[2020.day21]
+part1 = "mxmxvkd,#kfcds"  # a # in the answer
+"#;
+
+        let answers = ExpectedAnswers::parse(text).unwrap();
+
+        assert_eq!(Some("997899"), answers.get("2020.day1", 1));
+        assert_eq!(Some("131248694"), answers.get("2020.day1", 2));
+        assert_eq!(Some("18449208814679"), answers.get("2020.day20", 1));
+        assert_eq!(None, answers.get("2020.day20", 2));
+        assert_eq!(None, answers.get("2019.day1", 1));
+        assert_eq!(Some("mxmxvkd,#kfcds"), answers.get("2020.day21", 1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err("1: answer outside of a [<year>.dayN] section".to_string()),
+            ExpectedAnswers::parse("part1 = 1").map(|_| ()));
+        assert_eq!(
+            Err("2: unknown key part3".to_string()),
+            ExpectedAnswers::parse("[2020.day1]\npart3 = 1").map(|_| ()));
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(Verdict::Correct, check(Some("42"), &Answer::Number(42)));
+        assert_eq!(Verdict::Wrong("42".to_string()), check(Some("42"), &Answer::Number(41)));
+        assert_eq!(Verdict::Wrong("42".to_string()), check(Some("42"), &Answer::Unsolved));
+        assert_eq!(Verdict::Unknown, check(None, &Answer::Number(41)));
+    }
+}
\ No newline at end of file
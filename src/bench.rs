@@ -0,0 +1,90 @@
+use crate::runner;
+use crate::runner::format_duration;
+use crate::solution::Day;
+use std::time::Duration;
+
+// The spread of times taken by one step over every benchmark iteration
+#[derive(Debug, PartialEq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration
+}
+
+impl Timings {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Timings {
+        samples.sort_unstable();
+        Timings {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1]
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BenchResult {
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings
+}
+
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> BenchResult {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations.max(1) {
+        let result = runner::run_day(day, input);
+        parse.push(result.parse_elapsed);
+        part1.push(result.part1.elapsed);
+        part2.push(result.part2.elapsed);
+    }
+
+    BenchResult {
+        parse: Timings::from_samples(parse),
+        part1: Timings::from_samples(part1),
+        part2: Timings::from_samples(part2)
+    }
+}
+
+pub fn print_header() {
+    println!("{:<6} {:<6} {:>12} {:>12} {:>12}", "day", "step", "min", "median", "max");
+}
+
+pub fn print_result(day: &Day, result: &BenchResult) {
+    let steps = [
+        ("parse", &result.parse),
+        ("part1", &result.part1),
+        ("part2", &result.part2)
+    ];
+
+    for (step, timings) in &steps {
+        println!(
+            "{:<6} {:<6} {:>12} {:>12} {:>12}",
+            day.name(),
+            step,
+            format_duration(timings.min),
+            format_duration(timings.median),
+            format_duration(timings.max));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    #[test]
+    fn test_timings_from_samples() {
+        let samples = [5, 1, 4, 2, 3]
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect();
+
+        let timings = Timings::from_samples(samples);
+
+        assert_eq!(Duration::from_millis(1), timings.min);
+        assert_eq!(Duration::from_millis(3), timings.median);
+        assert_eq!(Duration::from_millis(5), timings.max);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,76 @@
+use crate::error::AocError;
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+// Cooperative cancellation for long running solvers. The runner sets a deadline for
+// the thread a day runs on; solvers call check from their loops and give up with a
+// "timed out" error once it has passed. A solver that never calls check can't be
+// stopped: it runs to the end however long that takes, and the timeout only means
+// the runner, which checks once more when a part finishes, throws its answer away.
+// So every loop that might run for a long time should call check or check_every.
+
+thread_local! {
+    static DEADLINE: Cell<Option<(Instant, Duration)>> = const { Cell::new(None) };
+}
+
+// Run f with a deadline of timeout from now on this thread, restoring the previous
+// deadline afterwards
+pub fn with_timeout<R, F: FnOnce() -> R>(timeout: Option<Duration>, f: F) -> R {
+
+    struct Restore(Option<(Instant, Duration)>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DEADLINE.with(|deadline| deadline.set(self.0));
+        }
+    }
+
+    let deadline = timeout.map(|timeout| (Instant::now() + timeout, timeout));
+    let _restore = Restore(DEADLINE.with(|d| d.replace(deadline)));
+
+    f()
+}
+
+pub fn check() -> Result<(), AocError> {
+    match DEADLINE.with(Cell::get) {
+        Some((deadline, timeout)) if Instant::now() >= deadline => Err(timed_out(timeout)),
+        _ => Ok(())
+    }
+}
+
+// Only look at the clock every 65536 calls, for loops too tight to check every time
+pub fn check_every(i: usize) -> Result<(), AocError> {
+    if i.is_multiple_of(1 << 16) {
+        check()
+    } else {
+        Ok(())
+    }
+}
+
+pub fn timed_out(timeout: Duration) -> AocError {
+    AocError::new(format!("timed out after {}", crate::runner::format_duration(timeout)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cancel::*;
+    use std::thread;
+
+    #[test]
+    fn test_check_times_out() {
+        let result: Result<(), AocError> = with_timeout(Some(Duration::from_millis(10)), || {
+            loop {
+                check()?;
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+
+        assert_eq!(Err(timed_out(Duration::from_millis(10))), result);
+        assert_eq!(Ok(()), check());
+    }
+
+    #[test]
+    fn test_no_timeout() {
+        assert_eq!(Ok(()), with_timeout(None, check));
+    }
+}
@@ -0,0 +1,528 @@
+use aoc::answers;
+use aoc::client;
+use aoc::input::InputSource;
+use aoc::memory;
+use aoc::picture::{Colour, Palette};
+use aoc::report::Format;
+use aoc::submit;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+usage: aoc [list | bench | verify | fetch] [all | dayN] [options]
+       aoc submit dayN <part> [options]
+       aoc watch dayN [options]
+       aoc draw dayN <part> <path> [--scale <n>] [--palette <colours>] [options]
+       aoc new dayN [--title <title>] [--year <year>]
+       aoc gen dayN [--size <n>] [--seed <n>] [--year <year>]
+
+  list                  list the registered days of the year
+  bench                 time parsing, part 1 and part 2 over several iterations
+  verify                check the answers against the known answers file
+  fetch                 download missing inputs into input/ using the session token
+                        in $AOC_SESSION or .aoc-session
+  submit                solve one part of a day and post the answer, unless the
+                        history of earlier attempts shows it is wrong
+  watch                 re-run a day whenever its input or an example file beside it
+                        (input/<year>/dayN_<name>.txt) changes, showing what changed
+  draw                  save a picture of what a part ends up with, for the days that
+                        have one (day 11's seats and day 20's image in 2020), to a .pbm,
+                        .pgm or .ppm image
+  new                   start a new day: write src/yearNNNN/dayNN.rs from a skeleton,
+                        add it to the year's registry and create an empty input,
+                        setting up the year first if it is a new one
+  gen                   print a made up input for a day, of almost any size, for profiling
+  all                   run every registered day (the default)
+  dayN                  run a single day
+
+options:
+  --year <year>         the event the days are from (default the latest one there are
+                        solutions for)
+  --input <path>        read the day's input from <path> instead of input/<year>/dayN.txt
+  --input -             read the day's input from stdin
+  --example <name>      use one of the day's worked examples as its input (see aoc list);
+                        verify then checks the answers given in the puzzle description
+  --time                show how long parsing and each part took
+  --memory              show what parsing and each part allocated: total bytes, number
+                        of allocations and peak heap (needs --features alloc-stats)
+  --format <format>     print results as text (the default), json or csv
+  --iterations <n>      number of bench iterations (default 10)
+  --answers <path>      known answers file for verify (default answers.toml)
+  --jobs <n>            run up to <n> days at once (default 1; bench always runs one at a time)
+  --timeout <seconds>   stop a day that runs for longer than this and report it as timed out
+  --base-url <url>      the puzzle website for fetch and submit (default https://adventofcode.com)
+  --history <path>      record of submitted answers (default submissions.log)
+  --title <title>       the puzzle's title for new (default \"Day N\")
+  --size <n>            how big an input gen makes: lines, tiles or whatever the day
+                        counts (default the size of a real input). A few days have a
+                        limit, e.g. in 2020 day 9 makes at most 400 numbers and day 20
+                        at most 12 tiles a side
+  --seed <n>            gen makes the same input for the same seed (default 0)
+  --scale <n>           how many pixels across draw makes each cell (default 4)
+  --palette <colours>   change the colours draw uses for cells drawn as a character,
+                        e.g. O=ff0000,#=000080 (by default . is white, # black, L green
+                        and O red)";
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_JOBS: usize = 1;
+const DEFAULT_SCALE: usize = 4;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    List(u32),
+    Run(Options),
+    Bench(Options),
+    Verify(Options),
+    Fetch(Options),
+    Submit(Options, u32),
+    Watch(Options),
+    Draw(Options, u32, PathBuf),
+    New(u32, u32, String),
+    Gen(u32, u32, Option<usize>, u64)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub year: u32,
+    pub day_or_all: String,
+    pub input: Option<InputSource>,
+    pub time: bool,
+    pub memory: bool,
+    pub format: Format,
+    pub iterations: usize,
+    pub answers: PathBuf,
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub base_url: String,
+    pub history: PathBuf,
+    pub title: Option<String>,
+    pub example: Option<String>,
+    pub size: Option<usize>,
+    pub seed: u64,
+    pub scale: usize,
+    pub palette: Vec<(char, Colour)>
+}
+
+impl Options {
+    pub fn includes(&self, day_name: &str) -> bool {
+        self.day_or_all == "all" || self.day_or_all == day_name
+    }
+
+    pub fn palette(&self) -> Palette {
+        let mut palette = Palette::default();
+        for (c, colour) in &self.palette {
+            palette.set(*c, *colour);
+        }
+        palette
+    }
+}
+
+impl Command {
+    pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+
+        fn value<I: Iterator<Item = String>>(args: &mut I, option: &str) -> Result<String, String> {
+            args.next().ok_or_else(|| format!("{} needs a value", option))
+        }
+
+        fn positive(value: &str, option: &str) -> Result<usize, String> {
+            value
+                .parse()
+                .ok()
+                .filter(|n| *n > 0)
+                .ok_or_else(|| format!("{} needs a positive number", option))
+        }
+
+        let mut positional: Vec<String> = Vec::new();
+        let mut options = Options {
+            year: aoc::latest_year(),
+            day_or_all: "all".to_string(),
+            input: None,
+            time: false,
+            memory: false,
+            format: Format::Text,
+            iterations: DEFAULT_ITERATIONS,
+            answers: PathBuf::from(answers::DEFAULT_PATH),
+            jobs: DEFAULT_JOBS,
+            timeout: None,
+            base_url: client::DEFAULT_BASE_URL.to_string(),
+            history: PathBuf::from(submit::DEFAULT_HISTORY_PATH),
+            title: None,
+            example: None,
+            size: None,
+            seed: 0,
+            scale: DEFAULT_SCALE,
+            palette: Vec::new()
+        };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" | "-y" => {
+                    options.year = value(&mut args, &arg)?
+                        .parse()
+                        .ok()
+                        .filter(|year| *year >= 2015)
+                        .ok_or_else(|| format!("{} needs a year from 2015 on", arg))?;
+                },
+                "--input" | "-i" => {
+                    options.input = Some(InputSource::from_arg(&value(&mut args, &arg)?));
+                },
+                "--time" => options.time = true,
+                "--memory" if !memory::ENABLED => {
+                    return Err(format!("{} needs aoc built with --features alloc-stats", arg));
+                },
+                "--memory" => options.memory = true,
+                "--format" | "-f" => options.format = value(&mut args, &arg)?.parse()?,
+                "--iterations" | "-n" => options.iterations = positive(&value(&mut args, &arg)?, &arg)?,
+                "--jobs" | "-j" => options.jobs = positive(&value(&mut args, &arg)?, &arg)?,
+                "--timeout" | "-t" => {
+                    let seconds = value(&mut args, &arg)?
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|s| s.is_finite() && *s > 0.0)
+                        .ok_or_else(|| format!("{} needs a positive number of seconds", arg))?;
+                    options.timeout = Some(Duration::from_secs_f64(seconds));
+                },
+                "--answers" => options.answers = PathBuf::from(value(&mut args, &arg)?),
+                "--base-url" => options.base_url = value(&mut args, &arg)?,
+                "--history" => options.history = PathBuf::from(value(&mut args, &arg)?),
+                "--title" => options.title = Some(value(&mut args, &arg)?),
+                "--example" | "-e" => options.example = Some(value(&mut args, &arg)?),
+                "--size" => options.size = Some(positive(&value(&mut args, &arg)?, &arg)?),
+                "--seed" => {
+                    options.seed = value(&mut args, &arg)?
+                        .parse()
+                        .map_err(|_| format!("{} needs a whole number", arg))?;
+                },
+                "--scale" => options.scale = positive(&value(&mut args, &arg)?, &arg)?,
+                "--palette" => options.palette.extend(Palette::parse_changes(&value(&mut args, &arg)?)?),
+                "-" => positional.push(arg),
+                _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg)),
+                _ => positional.push(arg)
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let mut watching = false;
+
+        let command = match positional.next() {
+            Some(ref c) if c == "list" => return Ok(Command::List(options.year)),
+            Some(ref c) if c == "bench" => Command::Bench,
+            Some(ref c) if c == "verify" => Command::Verify,
+            Some(ref c) if c == "fetch" => Command::Fetch,
+            Some(ref c) if c == "new" => {
+                let day: u32 = positional
+                    .next()
+                    .and_then(|day| day.strip_prefix("day").and_then(|n| n.parse().ok()))
+                    .filter(|day| (1..=25).contains(day))
+                    .ok_or_else(|| "new needs a day from day1 to day25, e.g. aoc new day21".to_string())?;
+                if let Some(arg) = positional.next() {
+                    return Err(format!("unexpected argument {}", arg));
+                }
+                let title = options.title.unwrap_or_else(|| format!("Day {}", day));
+                return Ok(Command::New(options.year, day, title));
+            },
+            Some(ref c) if c == "gen" => {
+                let day: u32 = positional
+                    .next()
+                    .and_then(|day| day.strip_prefix("day").and_then(|n| n.parse().ok()))
+                    .ok_or_else(|| "gen needs a day, e.g. aoc gen day7 --size 10000".to_string())?;
+                if let Some(arg) = positional.next() {
+                    return Err(format!("unexpected argument {}", arg));
+                }
+                return Ok(Command::Gen(options.year, day, options.size, options.seed));
+            },
+            Some(ref c) if c == "watch" => {
+                watching = true;
+                Command::Watch
+            },
+            Some(ref c) if c == "submit" => {
+                let usage = || "submit needs a day and a part, e.g. aoc submit day7 1".to_string();
+                options.day_or_all = positional.next().filter(|day| day != "all").ok_or_else(usage)?;
+                let part = positional
+                    .next()
+                    .and_then(|part| part.parse().ok())
+                    .filter(|part| *part == 1 || *part == 2)
+                    .ok_or_else(usage)?;
+                if let Some(arg) = positional.next() {
+                    return Err(format!("unexpected argument {}", arg));
+                }
+                return Ok(Command::Submit(options, part));
+            },
+            Some(ref c) if c == "draw" => {
+                let usage = || "draw needs a day, a part and where to save the picture, e.g. aoc draw day20 2 day20.ppm".to_string();
+                options.day_or_all = positional.next().filter(|day| day != "all").ok_or_else(usage)?;
+                let part = positional
+                    .next()
+                    .and_then(|part| part.parse().ok())
+                    .filter(|part| *part == 1 || *part == 2)
+                    .ok_or_else(usage)?;
+                let path = positional.next().map(PathBuf::from).ok_or_else(usage)?;
+                if let Some(arg) = positional.next() {
+                    return Err(format!("unexpected argument {}", arg));
+                }
+                return Ok(Command::Draw(options, part, path));
+            },
+            Some(day_or_all) => {
+                options.day_or_all = day_or_all;
+                Command::Run
+            },
+            None => Command::Run
+        };
+
+        if let Some(day_or_all) = positional.next() {
+            if options.day_or_all != "all" {
+                return Err(format!("unexpected argument {}", day_or_all));
+            }
+            options.day_or_all = day_or_all;
+        }
+
+        if let Some(arg) = positional.next() {
+            return Err(format!("unexpected argument {}", arg));
+        }
+
+        if options.day_or_all == "all" && options.input.is_some() {
+            return Err("--input needs a single day, e.g. aoc day7 --input my_input.txt".to_string());
+        }
+
+        if options.example.is_some() && (options.day_or_all == "all" || options.input.is_some()) {
+            return Err("--example needs a single day and no --input, e.g. aoc day11 --example seats".to_string());
+        }
+
+        if watching {
+            if options.day_or_all == "all" {
+                return Err("watch needs a single day, e.g. aoc watch day20".to_string());
+            }
+            if options.input == Some(InputSource::Stdin) {
+                return Err("watch needs an input file, not stdin".to_string());
+            }
+        }
+
+        Ok(command(options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        Command::parse(args.split_whitespace().map(|a| a.to_string()))
+    }
+
+    fn options(day_or_all: &str) -> Options {
+        Options {
+            year: aoc::latest_year(),
+            day_or_all: day_or_all.to_string(),
+            input: None,
+            time: false,
+            memory: false,
+            format: Format::Text,
+            iterations: DEFAULT_ITERATIONS,
+            answers: PathBuf::from(answers::DEFAULT_PATH),
+            jobs: DEFAULT_JOBS,
+            timeout: None,
+            base_url: client::DEFAULT_BASE_URL.to_string(),
+            history: PathBuf::from(submit::DEFAULT_HISTORY_PATH),
+            title: None,
+            example: None,
+            size: None,
+            seed: 0,
+            scale: DEFAULT_SCALE,
+            palette: Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_parse_defaults_to_all() {
+        assert_eq!(Ok(Command::Run(options("all"))), parse(""));
+    }
+
+    #[test]
+    fn test_parse_memory() {
+        let expected = Options {
+            memory: true,
+            ..options("day14")
+        };
+
+        if memory::ENABLED {
+            assert_eq!(Ok(Command::Run(expected)), parse("day14 --memory"));
+        } else {
+            assert!(parse("day14 --memory").is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_year() {
+        let expected = Options {
+            year: 2019,
+            ..options("day7")
+        };
+
+        assert_eq!(Ok(Command::Run(expected)), parse("day7 --year 2019"));
+        assert_eq!(Ok(Command::List(2021)), parse("list -y 2021"));
+        assert_eq!(Ok(Command::New(2021, 1, "Day 1".to_string())), parse("new day1 --year 2021"));
+        assert!(parse("day7 --year 20").is_err());
+        assert!(parse("day7 --year").is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let expected = Options {
+            input: Some(InputSource::File("other.txt".into())),
+            ..options("day7")
+        };
+
+        assert_eq!(Ok(Command::Run(expected)), parse("day7 --input other.txt"));
+    }
+
+    #[test]
+    fn test_parse_stdin() {
+        let expected = Options {
+            input: Some(InputSource::Stdin),
+            ..options("day7")
+        };
+
+        assert_eq!(Ok(Command::Run(expected)), parse("--input - day7"));
+    }
+
+    #[test]
+    fn test_parse_input_needs_a_day() {
+        assert!(parse("--input other.txt").is_err());
+        assert!(parse("day7 --input").is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let expected = Options {
+            iterations: 3,
+            ..options("day17")
+        };
+
+        assert_eq!(Ok(Command::Bench(expected)), parse("bench day17 -n 3"));
+        assert_eq!(Ok(Command::Bench(options("all"))), parse("bench"));
+        assert!(parse("bench day17 --iterations 0").is_err());
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let expected = Options {
+            answers: PathBuf::from("other.toml"),
+            ..options("all")
+        };
+
+        assert_eq!(Ok(Command::Verify(expected)), parse("verify --answers other.toml"));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let expected = Options {
+            format: Format::Json,
+            ..options("all")
+        };
+
+        assert_eq!(Ok(Command::Run(expected)), parse("--format json"));
+        assert!(parse("--format yaml").is_err());
+    }
+
+    #[test]
+    fn test_parse_jobs() {
+        let expected = Options {
+            jobs: 4,
+            ..options("all")
+        };
+
+        assert_eq!(Ok(Command::Run(expected)), parse("--jobs 4"));
+        assert_eq!(Ok(Command::Verify(Options { jobs: 2, ..options("all") })), parse("verify -j 2"));
+        assert!(parse("--jobs 0").is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        let expected = Options {
+            timeout: Some(Duration::from_millis(1500)),
+            ..options("day15")
+        };
+
+        assert_eq!(Ok(Command::Run(expected)), parse("day15 --timeout 1.5"));
+        assert!(parse("--timeout 0").is_err());
+        assert!(parse("--timeout soon").is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        let expected = Options {
+            base_url: "http://localhost:8080".to_string(),
+            ..options("day3")
+        };
+
+        assert_eq!(Ok(Command::Fetch(expected)), parse("fetch day3 --base-url http://localhost:8080"));
+        assert_eq!(Ok(Command::Fetch(options("all"))), parse("fetch"));
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        let expected = Options {
+            history: PathBuf::from("other.log"),
+            ..options("day7")
+        };
+
+        assert_eq!(Ok(Command::Submit(expected, 2)), parse("submit day7 2 --history other.log"));
+        assert!(parse("submit day7").is_err());
+        assert!(parse("submit day7 3").is_err());
+        assert!(parse("submit all 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_draw() {
+        let expected = Options {
+            scale: 2,
+            palette: vec![('O', Colour::rgb(0, 255, 0))],
+            ..options("day20")
+        };
+
+        assert_eq!(
+            Ok(Command::Draw(expected, 2, PathBuf::from("day20.ppm"))),
+            parse("draw day20 2 day20.ppm --scale 2 --palette O=00ff00"));
+        assert!(parse("draw day20 2").is_err());
+        assert!(parse("draw all 1 seats.pbm").is_err());
+        assert!(parse("draw day11 1 seats.pbm --palette L=green").is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        assert_eq!(Ok(Command::Watch(options("day20"))), parse("watch day20"));
+        assert!(parse("watch").is_err());
+        assert!(parse("watch day20 --input -").is_err());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(Ok(Command::New(2020, 21, "Day 21".to_string())), parse("new day21"));
+        assert_eq!(
+            Ok(Command::New(2020, 22, "Crab Combat".to_string())),
+            Command::parse(vec!["new", "day22", "--title", "Crab Combat"].into_iter().map(String::from)));
+        assert!(parse("new day26").is_err());
+        assert!(parse("new").is_err());
+    }
+
+    #[test]
+    fn test_parse_gen() {
+        assert_eq!(Ok(Command::Gen(2020, 7, None, 0)), parse("gen day7"));
+        assert_eq!(Ok(Command::Gen(2020, 9, Some(100000), 42)), parse("gen day9 --size 100000 --seed 42"));
+        assert!(parse("gen").is_err());
+        assert!(parse("gen day7 --size 0").is_err());
+        assert!(parse("gen day7 --seed -1").is_err());
+    }
+
+    #[test]
+    fn test_parse_example() {
+        let expected = Options {
+            example: Some("seats".to_string()),
+            ..options("day11")
+        };
+
+        assert_eq!(Ok(Command::Run(expected)), parse("day11 --example seats"));
+        assert!(parse("--example seats").is_err());
+        assert!(parse("day11 --example seats --input other.txt").is_err());
+    }
+}
@@ -0,0 +1,250 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// The session token is the value of the "session" cookie set by adventofcode.com
+// after logging in. It is read from this env var, or failing that this file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".aoc-session";
+
+// Be gentle with the server: never make requests closer together than this
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+const USER_AGENT: &str = "github.com/megrogan/AdventOfCode2020 by matthewgrogan";
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached,
+    Downloaded
+}
+
+// A client for the puzzle website, for one year's event. Every request is spaced at
+// least min_interval after the previous one.
+pub struct Client {
+    pub base_url: String,
+    pub year: u32,
+    pub min_interval: Duration,
+    session: Option<String>,
+    agent: ureq::Agent,
+    last_request: Option<Instant>
+}
+
+impl Client {
+    pub fn new(base_url: &str, year: u32, session: Option<String>) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            year,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            session,
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build(),
+            last_request: None
+        }
+    }
+
+    // Make sure the input for day is at path, downloading it only if it isn't there
+    // already. A cached input is never downloaded again.
+    pub fn fetch_input(&mut self, day: u32, path: &Path) -> Result<Fetched, String> {
+        if is_cached(path) {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.get(&format!("/{}/day/{}/input", self.year, day))?;
+
+        if input.trim().is_empty() {
+            return Err(format!("the input for day{} was empty", day));
+        }
+
+        // Write to a temporary file first so an interrupted download can't leave a
+        // partial input that would then be treated as cached
+        let partial = path.with_extension("part");
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| format!("cannot create {}: {}", dir.display(), err))?;
+        }
+        fs::write(&partial, &input).map_err(|err| format!("cannot write {}: {}", partial.display(), err))?;
+        fs::rename(&partial, path).map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+
+        Ok(Fetched::Downloaded)
+    }
+
+    pub fn get(&mut self, path: &str) -> Result<String, String> {
+        let request = self.request("GET", path)?;
+        read_response(request.call())
+    }
+
+    pub fn post_form(&mut self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let request = self.request("POST", path)?;
+        read_response(request.send_form(form))
+    }
+
+    fn request(&mut self, method: &str, path: &str) -> Result<ureq::Request, String> {
+        let session = self.session.clone().ok_or_else(|| format!(
+            "no session token, set {} or put it in {}",
+            SESSION_VAR,
+            SESSION_FILE))?;
+
+        self.wait_for_turn();
+
+        Ok(self
+            .agent
+            .request(method, &format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT))
+    }
+
+    fn wait_for_turn(&mut self) {
+        if let Some(last) = self.last_request {
+            let since = last.elapsed();
+            if since < self.min_interval {
+                thread::sleep(self.min_interval - since);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| format!("cannot read response: {}", err)),
+        Err(ureq::Error::Status(404, _)) => Err("not found, is the puzzle unlocked yet?".to_string()),
+        Err(ureq::Error::Status(400, _)) | Err(ureq::Error::Status(500, _)) =>
+            Err("request rejected, is the session token still valid?".to_string()),
+        Err(ureq::Error::Status(code, response)) => Err(format!(
+            "HTTP {}: {}",
+            code,
+            response.into_string().unwrap_or_default().lines().next().unwrap_or(""))),
+        Err(err) => Err(err.to_string())
+    }
+}
+
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).map(|m| m.len() > 0).unwrap_or(false)
+}
+
+// The session token from the environment or the session file, if either has one
+pub fn load_session() -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| fs::read_to_string(SESSION_FILE).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::client::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+
+    // A local HTTP server that answers each connection with the next canned response
+    // and records the request line and headers it was sent
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        thread::spawn(move || {
+            for (code, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+                let mut content = vec![0; content_length];
+                std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                recorded.lock().unwrap().push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    code,
+                    body.len(),
+                    body).unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn client(url: &str) -> Client {
+        let mut client = Client::new(url, 2020, Some("abc123".to_string()));
+        client.min_interval = Duration::from_millis(0);
+        client
+    }
+
+    #[test]
+    fn test_fetch_downloads_then_uses_cache() {
+        let (url, requests) = serve(vec![(200, "1721\n979\n")]);
+        let path = temp_dir("fetch").join("input/day1.txt");
+        let mut client = client(&url);
+
+        assert_eq!(Ok(Fetched::Downloaded), client.fetch_input(1, &path));
+        assert_eq!(Ok(Fetched::Cached), client.fetch_input(1, &path));
+
+        assert_eq!("1721\n979\n", fs::read_to_string(&path).unwrap());
+        let requests = requests.lock().unwrap();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2020/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_fetch_reports_errors_without_caching() {
+        let (url, _) = serve(vec![(404, "Please don't repeatedly request this endpoint")]);
+        let path = temp_dir("fetch-error").join("day25.txt");
+
+        let result = client(&url).fetch_input(25, &path);
+
+        assert_eq!(Err("not found, is the puzzle unlocked yet?".to_string()), result);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_fetch_needs_a_session() {
+        let path = temp_dir("fetch-session").join("day1.txt");
+
+        let result = Client::new("http://127.0.0.1:9", 2020, None).fetch_input(1, &path);
+
+        assert!(result.unwrap_err().starts_with("no session token"));
+    }
+
+    #[test]
+    fn test_requests_are_rate_limited() {
+        let (url, _) = serve(vec![(200, "1"), (200, "2")]);
+        let dir = temp_dir("fetch-rate");
+        let mut client = client(&url);
+        client.min_interval = Duration::from_millis(200);
+
+        let start = Instant::now();
+        client.fetch_input(1, &dir.join("day1.txt")).unwrap();
+        client.fetch_input(2, &dir.join("day2.txt")).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,227 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// The error returned by every parser and solver. Parse errors carry the line and column
+// (both counted from 1) of the offending text so a bad input can be fixed without a
+// debugger. The runner fills in the day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AocError {
+    pub day: Option<u32>,
+    pub location: Option<Location>,
+    pub message: String
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub text: String
+}
+
+impl AocError {
+    // An error that isn't tied to a place in the input, e.g. a puzzle with no solution
+    pub fn new<S: Into<String>>(message: S) -> AocError {
+        AocError {
+            day: None,
+            location: None,
+            message: message.into()
+        }
+    }
+
+    pub fn at<S: Into<String>>(line: usize, column: usize, text: &str, message: S) -> AocError {
+        AocError {
+            day: None,
+            location: Some(Location {
+                line,
+                column,
+                text: text.to_string()
+            }),
+            message: message.into()
+        }
+    }
+
+    // An error for part, which must be a slice of input (as returned by lines, split,
+    // trim etc.). The line and column are worked out from where part sits in input.
+    pub fn within<S: Into<String>>(input: &str, part: &str, message: S) -> AocError {
+        match offset_of(input, part) {
+            Some(offset) => {
+                let line_start = input[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+                let line = input[..offset].matches('\n').count() + 1;
+                let column = input[line_start..offset].chars().count() + 1;
+                let text = input[line_start..].lines().next().unwrap_or("");
+                AocError::at(line, column, text, message)
+            },
+            None => AocError::new(format!("{}: {:?}", message.into(), part))
+        }
+    }
+
+    pub fn in_day(mut self, day: u32) -> AocError {
+        self.day = Some(day);
+        self
+    }
+
+    // Move an error raised on the first line of some text to the line the text came from
+    pub fn on_line(mut self, line: usize) -> AocError {
+        if let Some(location) = &mut self.location {
+            location.line += line - 1;
+        }
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day{}: ", day)?;
+        }
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "line {}, column {}: {} in {:?}",
+                location.line,
+                location.column,
+                self.message,
+                location.text),
+            None => write!(f, "{}", self.message)
+        }
+    }
+}
+
+impl Error for AocError {}
+
+// A numbered line of input. Errors created from a line point at the part of the line
+// that was wrong.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Line<'a> {
+        Line {
+            number,
+            text
+        }
+    }
+
+    // part should be a slice of the line; anything else points at the start of the line
+    pub fn error<S: Into<String>>(&self, part: &str, message: S) -> AocError {
+        let column = offset_of(self.text, part)
+            .map(|offset| self.text[..offset].chars().count() + 1)
+            .unwrap_or(1);
+        AocError::at(self.number, column, self.text, message)
+    }
+
+    // An error for something missing from the end of the line
+    pub fn missing<S: Into<String>>(&self, what: S) -> AocError {
+        let column = self.text.trim_end().chars().count() + 1;
+        AocError::at(self.number, column, self.text, format!("expected {}", what.into()))
+    }
+
+    pub fn parse<T: FromStr>(&self, part: &str, what: &str) -> Result<T, AocError> {
+        part.trim()
+            .parse()
+            .map_err(|_| self.error(part.trim(), format!("expected {}, found {:?}", what, part.trim())))
+    }
+
+    // Take the next item from an iterator over parts of the line
+    pub fn next<I: Iterator<Item = &'a str>>(&self, parts: &mut I, what: &str) -> Result<&'a str, AocError> {
+        parts.next().ok_or_else(|| self.missing(what))
+    }
+}
+
+// Number the lines of the input from 1
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+// Number the lines of part, a slice of input, by where they sit in input
+pub fn lines_within<'a>(input: &str, part: &'a str) -> impl Iterator<Item = Line<'a>> {
+    let first = offset_of(input, part)
+        .map(|offset| input[..offset].matches('\n').count() + 1)
+        .unwrap_or(1);
+
+    part
+        .lines()
+        .enumerate()
+        .map(move |(i, text)| Line::new(first + i, text))
+}
+
+// The byte offset of part within whole, if part is a slice of whole
+fn offset_of(whole: &str, part: &str) -> Option<usize> {
+    let start = whole.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+    if part_start >= start && part_start + part.len() <= start + whole.len() {
+        Some(part_start - start)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::*;
+
+    #[test]
+    fn test_within() {
+        let input = "1-3 a: abcde\n1-x b: cdefg";
+        let part = &input[15..16];
+
+        let err = AocError::within(input, part, "expected a number").in_day(2);
+
+        assert_eq!(Some(Location { line: 2, column: 3, text: "1-x b: cdefg".to_string() }), err.location);
+        assert_eq!(r#"day2: line 2, column 3: expected a number in "1-x b: cdefg""#, err.to_string());
+    }
+
+    #[test]
+    fn test_within_unrelated_text() {
+        let err = AocError::within("abc", "xyz", "unexpected");
+
+        assert_eq!(None, err.location);
+        assert_eq!(r#"unexpected: "xyz""#, err.to_string());
+    }
+
+    #[test]
+    fn test_line_parse() {
+        let line = lines("F10\nNx3").nth(1).unwrap();
+
+        let number: Result<u32, AocError> = line.parse(&line.text[1..], "a number");
+
+        assert_eq!(
+            r#"line 2, column 2: expected a number, found "x3" in "Nx3""#,
+            number.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_line_missing() {
+        let line = Line::new(4, "mem[8] ");
+        let mut parts = line.text.split(" = ");
+        parts.next();
+
+        let err = line.next(&mut parts, "a value").unwrap_err();
+
+        assert_eq!(r#"line 4, column 7: expected a value in "mem[8] ""#, err.to_string());
+    }
+
+    #[test]
+    fn test_lines_within() {
+        let input = "a\nb\n\nc\nd";
+        let section = input.split("\n\n").nth(1).unwrap();
+
+        let numbers: Vec<usize> = lines_within(input, section).map(|line| line.number).collect();
+
+        assert_eq!(vec![4, 5], numbers);
+    }
+
+    #[test]
+    fn test_on_line() {
+        let err = AocError::at(1, 5, "1 + x", "unexpected x").on_line(12);
+
+        assert_eq!(12, err.location.unwrap().line);
+    }
+}
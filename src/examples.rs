@@ -0,0 +1,72 @@
+// The worked examples from the puzzle descriptions, with the answers the descriptions
+// give for them. The inputs live beside the real ones as input/<year>/dayN_<name>.txt
+// (so watch picks them up too) and are compiled in, so the tests and aoc --example
+// <name> run exactly the same text. An answer of None means the description doesn't
+// give one for that part. Each year lists its own examples in its examples module.
+use crate::find_year;
+
+pub struct Example {
+    pub day: u32,
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>
+}
+
+macro_rules! example {
+    ($year:expr, $day:expr, $name:expr, $part1:expr, $part2:expr) => {
+        Example {
+            day: $day,
+            name: $name,
+            input: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/input/", $year, "/day", $day, "_", $name, ".txt")),
+            part1: $part1,
+            part2: $part2
+        }
+    };
+}
+
+pub(crate) use example;
+
+pub fn for_day(year: u32, day: u32) -> impl Iterator<Item = &'static Example> {
+    let examples = find_year(year).map(|year| year.examples).unwrap_or_default();
+    examples.iter().filter(move |example| example.day == day)
+}
+
+pub fn find(year: u32, day: u32, name: &str) -> Option<&'static Example> {
+    for_day(year, day).find(|example| example.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::YEARS;
+
+    // Only the parts with an expected answer are run, as some parts are slow or
+    // meaningless on their examples
+    #[test]
+    fn test_examples_give_the_expected_answers() {
+        for year in YEARS {
+            let days = year.days();
+
+            for example in year.examples {
+                let day = days.iter().find(|day| day.number == example.day).unwrap();
+                let solution = day.parse(example.input).unwrap();
+
+                for (part, expected) in [(1, example.part1), (2, example.part2)].iter() {
+                    if let Some(expected) = expected {
+                        let answer = match part {
+                            1 => solution.part1(),
+                            _ => solution.part2()
+                        };
+                        assert_eq!(
+                            expected.to_string(),
+                            answer.unwrap().to_string(),
+                            "{} day{} example {}",
+                            year.number,
+                            example.day,
+                            example.name);
+                    }
+                }
+            }
+        }
+    }
+}
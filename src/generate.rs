@@ -0,0 +1,172 @@
+// Generators of valid puzzle inputs of any size, for profiling the solvers and checking
+// how they scale. The same day, size and seed always give the same input. Each
+// generator works around the assumptions its solver makes of a real input (such as
+// the answers fitting in 64 bits), which are noted where they limit the input. Each
+// year lists its own generators in its generate module.
+use crate::find_year;
+
+pub type Generator = fn(&mut Rng, usize) -> Result<String, String>;
+
+pub fn has_generator(year: u32, day: u32) -> bool {
+    find_year(year).is_ok_and(|year| year.generators.iter().any(|(d, _, _)| *d == day))
+}
+
+// An input for day with size items, or as many as the real input has
+pub fn generate(year: u32, day: u32, size: Option<usize>, seed: u64) -> Result<String, String> {
+    let (_, default_size, generator) = find_year(year)?
+        .generators
+        .iter()
+        .find(|(d, _, _)| *d == day)
+        .ok_or_else(|| format!("no generator for {} day{}", year, day))?;
+
+    generator(&mut Rng::new(seed), size.unwrap_or(*default_size))
+}
+
+// SplitMix64: small, fast and, unlike an external crate, guaranteed never to change
+// the inputs it gives for a seed
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng {
+            state: seed
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // A number from low to high inclusive
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "empty range {}..={}", low, high);
+        low + (self.next_u64() % ((high - low) as u64 + 1)) as i64
+    }
+
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0, n as i64 - 1) as usize
+    }
+
+    // True one time in n
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64().is_multiple_of(n)
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    pub fn letter(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+}
+
+pub fn at_least(day: u32, size: usize, minimum: usize, what: &str) -> Result<(), String> {
+    if size < minimum {
+        return Err(format!("day{} needs at least {} {}", day, minimum, what));
+    }
+    Ok(())
+}
+
+pub fn at_most(day: u32, size: usize, maximum: usize, what: &str) -> Result<(), String> {
+    if size > maximum {
+        return Err(format!("day{} can have at most {} {}", day, maximum, what));
+    }
+    Ok(())
+}
+
+pub fn lines<T: ToString>(items: &[T]) -> String {
+    items.iter().map(|item| item.to_string() + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::*;
+    use crate::solution::Answer;
+    use crate::YEARS;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+
+        assert_eq!((0..10).map(|_| a.next_u64()).collect::<Vec<_>>(), (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+        assert!((0..1000).map(|_| a.range(-3, 3)).all(|n| (-3..=3).contains(&n)));
+    }
+
+    #[test]
+    fn test_generate_is_deterministic() {
+        assert_eq!(generate(2020, 7, Some(50), 1), generate(2020, 7, Some(50), 1));
+        assert_ne!(generate(2020, 7, Some(50), 1), generate(2020, 7, Some(50), 2));
+        assert!(generate(2020, 25, None, 1).is_err());
+        assert!(generate(2019, 1, None, 1).is_err());
+        assert!(generate(2020, 5, Some(2000), 1).is_err());
+    }
+
+    // A simulation of every seat takes more than half a minute at this size, even
+    // optimised
+    #[test]
+    fn test_large_seat_layouts_are_quick() {
+        let start = Instant::now();
+
+        let layout = generate(2020, 11, Some(300), 1).unwrap();
+
+        assert!(start.elapsed() < Duration::from_secs(15), "took {:?}", start.elapsed());
+        assert_eq!(300, layout.lines().count());
+    }
+
+    // Day 9 of 2020 used to run out of sums for some seeds, and its numbers are closest
+    // to overflowing at the largest size
+    #[test]
+    fn test_xmas_data_solves_for_every_seed() {
+        let day = find_year(2020).unwrap().days().into_iter().find(|day| day.number == 9).unwrap();
+        for seed in 0..100 {
+            let input = generate(2020, 9, Some(400), seed).unwrap_or_else(|err| panic!("seed {}: {}", seed, err));
+            let solution = day.parse(&input).unwrap();
+
+            assert!(solution.part1().is_ok() && solution.part2().is_ok(), "seed {}", seed);
+        }
+        assert!(generate(2020, 9, Some(401), 0).is_err());
+    }
+
+    // Small inputs, as some solvers are slow unoptimised. Day 15 part 2 of 2020 always
+    // plays 30 million turns, so that part is left out.
+    #[test]
+    fn test_generated_inputs_solve() {
+        for year in YEARS {
+            for day in year.days().iter().filter(|day| has_generator(year.number, day.number)) {
+                let name = day.id();
+                for seed in 0..3 {
+                    let size = match (year.number, day.number) {
+                        (2020, 15) => 5,
+                        (2020, 17) | (2020, 20) => 3,
+                        _ => 60
+                    };
+                    let input = generate(year.number, day.number, Some(size), seed).unwrap();
+                    let solution = day.parse(&input).unwrap_or_else(|err| panic!("{} seed {}: {}", name, seed, err));
+
+                    let part1 = solution.part1().unwrap_or_else(|err| panic!("{} seed {}: {}", name, seed, err));
+                    assert_ne!(Answer::Unsolved, part1, "{}", name);
+
+                    if (year.number, day.number) != (2020, 15) {
+                        solution.part2().unwrap_or_else(|err| panic!("{} seed {}: {}", name, seed, err));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use crate::error::{lines, AocError, Line};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// Steps to the neighbours of a cell as (dx, dy), with y increasing downwards
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0), (1, 0),
+    (-1, 1), (0, 1), (1, 1)
+];
+
+// A rectangle of cells stored row by row and indexed by (x, y) from the top left.
+// Neighbours and rays stop at the edges, so callers never need a rim of padding.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect()
+        }
+    }
+
+    // Parse a map of characters, one row per non-blank line. cell turns a character
+    // into a cell, or None for a character that isn't allowed.
+    pub fn parse<F: Fn(char) -> Option<T>>(input: &str, cell: F) -> Result<Grid<T>, AocError> {
+        Grid::parse_lines(lines(input), cell)
+    }
+
+    // As parse, for a map that is one section of a larger input
+    pub fn parse_lines<'a, I, F>(lines: I, cell: F) -> Result<Grid<T>, AocError>
+    where
+        I: Iterator<Item = Line<'a>>,
+        F: Fn(char) -> Option<T> {
+
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        // Only the line ending is trimmed, so stray spaces are reported rather than
+        // quietly shifting a row
+        for line in lines.filter(|line| !line.text.trim_end_matches('\r').is_empty()) {
+            let text = line.text.trim_end_matches('\r');
+
+            for (i, c) in text.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => return Err(line.error(&text[i..], format!("unexpected {:?}", c)))
+                }
+            }
+
+            let found = text.chars().count();
+            match width {
+                Some(width) if width != found =>
+                    return Err(line.error(text, format!("expected {} cells, found {}", width, found))),
+                _ => width = Some(found)
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Grid { width, height, cells }),
+            None => Err(AocError::new("empty grid"))
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    // Empty for a column outside the grid, as get is, and so for any column of a grid
+    // with no columns
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let start = if x < self.width { x } else { self.cells.len() };
+        self.cells.iter().skip(start).step_by(self.width.max(1))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    // Every (x, y) in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // The cell one step from (x, y), if that is still inside the grid
+    pub fn step(&self, x: usize, y: usize, (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL.iter().filter_map(move |d| self.step(x, y, *d))
+    }
+
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_DIRECTIONS.iter().filter_map(move |d| self.step(x, y, *d))
+    }
+
+    // The cells seen looking from (x, y) in direction, nearest first, up to the edge
+    pub fn ray(&self, x: usize, y: usize, direction: (isize, isize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut position = (x, y);
+        std::iter::from_fn(move || {
+            position = self.step(position.0, position.1, direction)?;
+            Some(position)
+        })
+    }
+
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect()
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height]
+        }
+    }
+
+    // A quarter turn clockwise
+    pub fn rotate(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, self.height - 1 - x)].clone())
+    }
+
+    // Mirror left to right
+    pub fn flip(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| self[(self.width - 1 - x, y)].clone())
+    }
+
+    // The four rotations of the grid followed by the four rotations of its mirror image
+    pub fn orientations(&self) -> Vec<Grid<T>> {
+        let mut orientations = Vec::with_capacity(8);
+        for start in [self.clone(), self.flip()].iter() {
+            let mut grid = start.clone();
+            for _ in 0..4 {
+                let next = grid.rotate();
+                orientations.push(grid);
+                grid = next;
+            }
+        }
+        orientations
+    }
+
+    // The width by height rectangle with its top left at (x, y), which must fit
+    pub fn sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Grid<T> {
+        assert!(x + width <= self.width && y + height <= self.height, "sub grid out of bounds");
+        Grid::from_fn(width, height, |dx, dy| self[(x + dx, y + dy)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| panic!("({}, {}) is outside the {}x{} grid", x, y, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y).unwrap_or_else(|| panic!("({}, {}) is outside the {}x{} grid", x, y, width, height))
+    }
+}
+
+// One line per row, each cell written with its own Display
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(input, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = chars("ab\ncd\r\n\nef\n");
+
+        assert_eq!((2, 3), (grid.width(), grid.height()));
+        assert_eq!('c', grid[(0, 1)]);
+        assert_eq!(None, grid.get(2, 0));
+        assert_eq!("ab\ncd\nef\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_cell() {
+        let cell = |c| match c { '#' => Some(true), '.' => Some(false), _ => None };
+
+        let err = Grid::parse("#.#\n.x.", cell).unwrap_err();
+        let location = err.location.unwrap();
+        assert_eq!((2, 2), (location.line, location.column));
+
+        let err = Grid::parse("#.#\n..", cell).unwrap_err();
+        assert_eq!("expected 3 cells, found 2", err.message);
+
+        let err = Grid::parse("#.#\n #.", cell).unwrap_err();
+        assert_eq!((2, 1), (err.location.as_ref().unwrap().line, err.location.as_ref().unwrap().column));
+        assert!(Grid::parse("#.#\n.#.#", cell).is_err());
+
+        assert!(Grid::parse("\n\n", cell).is_err());
+    }
+
+    #[test]
+    fn test_neighbours_stop_at_the_edges() {
+        let grid = chars("abc\ndef\nghi");
+
+        let corner: Vec<char> = grid.neighbours8(0, 0).map(|p| grid[p]).collect();
+        assert_eq!(vec!['b', 'd', 'e'], corner);
+
+        let centre: Vec<char> = grid.neighbours4(1, 1).map(|p| grid[p]).collect();
+        assert_eq!(vec!['b', 'f', 'h', 'd'], centre);
+
+        assert_eq!(8, grid.neighbours8(1, 1).count());
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = chars("abc\ndef\nghi");
+
+        let ray: Vec<char> = grid.ray(0, 0, (1, 1)).map(|p| grid[p]).collect();
+        assert_eq!(vec!['e', 'i'], ray);
+        assert_eq!(0, grid.ray(0, 0, (-1, 0)).count());
+    }
+
+    #[test]
+    fn test_rotate_flip_and_sub_grid() {
+        let grid = chars("abc\ndef");
+
+        assert_eq!("da\neb\nfc\n", grid.rotate().to_string());
+        assert_eq!("cba\nfed\n", grid.flip().to_string());
+        assert_eq!(grid, grid.rotate().rotate().rotate().rotate());
+        assert_eq!("ef\n", grid.sub_grid(1, 1, 2, 1).to_string());
+        assert_eq!(vec!['c', 'f'], grid.column(2).copied().collect::<Vec<_>>());
+
+        let empty: Grid<char> = Grid::new(0, 3, ' ');
+        assert_eq!(0, empty.column(0).count());
+        assert_eq!(0, grid.column(3).count());
+        assert_eq!(0, grid.column(4).rev().count());
+
+        let orientations = grid.orientations();
+        assert_eq!(8, orientations.len());
+        assert!(orientations.contains(&chars("cf\nbe\nad")));
+    }
+}
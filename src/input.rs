@@ -0,0 +1,110 @@
+use crate::error::{lines, AocError, Line};
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::PathBuf;
+
+// Where a day's puzzle input comes from
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin
+}
+
+impl InputSource {
+    // "-" means stdin, anything else is a path
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path))
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path).map_err(|err| {
+                io::Error::new(err.kind(), format!("cannot read {}: {}", path.display(), err))
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+// A run of non-blank lines, e.g. one passport, one tile or one section of a puzzle
+// with several parts. The lines keep their numbers within the whole input.
+#[derive(Debug, Clone)]
+pub struct Record<'a> {
+    pub lines: Vec<Line<'a>>
+}
+
+impl<'a> Record<'a> {
+    pub fn first_line(&self) -> usize {
+        self.lines[0].number
+    }
+
+    // Everything after the first line, for records that start with a heading
+    pub fn body(&self) -> &[Line<'a>] {
+        &self.lines[1..]
+    }
+}
+
+// Split the input into records at blank lines. Lines of only whitespace count as
+// blank, and lines drops the \r of a \r\n ending, so an input saved on Windows or
+// with extra blank lines at the end splits the same as a clean one.
+pub fn records(input: &str) -> Vec<Record<'_>> {
+    let mut records = Vec::new();
+    let mut current = Vec::new();
+
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !current.is_empty() {
+                records.push(Record { lines: std::mem::take(&mut current) });
+            }
+        } else {
+            current.push(line);
+        }
+    }
+
+    if !current.is_empty() {
+        records.push(Record { lines: current });
+    }
+
+    records
+}
+
+// Take the next record of an input made of sections, what describing the one expected
+pub fn next_record<'a, I: Iterator<Item = Record<'a>>>(records: &mut I, what: &str) -> Result<Record<'a>, AocError> {
+    records.next().ok_or_else(|| AocError::new(format!("expected {}", what)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::*;
+
+    #[test]
+    fn test_records_tolerate_crlf_and_blank_lines() {
+        let input = "\r\na b\r\nc\r\n  \r\n\r\nd \r\n\r\n\r\n";
+
+        let records = records(input);
+
+        assert_eq!(2, records.len());
+        assert_eq!(2, records[0].first_line());
+        assert_eq!(vec!["a b", "c"], records[0].lines.iter().map(|line| line.text).collect::<Vec<_>>());
+        assert_eq!(6, records[1].first_line());
+        assert_eq!("d ", records[1].lines[0].text);
+        assert!(records[1].body().is_empty());
+    }
+
+    #[test]
+    fn test_next_record() {
+        let mut sections = records("rules\n\nmessages").into_iter();
+
+        assert_eq!(1, next_record(&mut sections, "the rules").unwrap().first_line());
+        assert_eq!(3, next_record(&mut sections, "the messages").unwrap().first_line());
+        assert_eq!("expected the tickets", next_record(&mut sections, "the tickets").unwrap_err().message);
+    }
+}
@@ -0,0 +1,99 @@
+// The puzzle solutions and the machinery for running them. The aoc binary is a thin
+// command line over this library; other tools can use the days directly, e.g.
+// aoc::year2020::day08::Program or aoc::year2020::day18::evaluate.
+//
+// Each event lives in a module of its own, yearNNNN, holding its days, worked
+// examples and input generators. Everything the days share, such as parsing helpers
+// and the grid, lives here at the top of the crate.
+#[macro_use] extern crate lazy_static;
+extern crate regex;
+
+use examples::Example;
+use generate::Generator;
+use solution::Day;
+
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod client;
+pub mod error;
+pub mod examples;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod memory;
+pub mod picture;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+#[cfg(test)]
+pub mod strategies;
+pub mod submit;
+pub mod watch;
+
+pub mod year2020;
+
+// One event's puzzles
+pub struct Year {
+    pub number: u32,
+    pub registry: fn() -> Vec<Day>,
+    pub examples: &'static [Example],
+    // Day, the size of the real input and a generator of inputs of any size
+    pub generators: &'static [(u32, usize, Generator)]
+}
+
+impl Year {
+    pub fn days(&self) -> Vec<Day> {
+        (self.registry)()
+    }
+}
+
+// Every event the runner knows about, oldest first. Adding one means adding its
+// module and a line here, which aoc new --year does along with its first day.
+pub const YEARS: &[Year] = &[
+    Year { number: 2020, registry: year2020::registry, examples: year2020::examples::EXAMPLES, generators: year2020::generate::GENERATORS },
+];
+
+pub fn find_year(number: u32) -> Result<&'static Year, String> {
+    YEARS.iter().find(|year| year.number == number).ok_or_else(|| {
+        let known: Vec<String> = YEARS.iter().map(|year| year.number.to_string()).collect();
+        format!("there are no puzzles for {}, only for {}", number, known.join(", "))
+    })
+}
+
+// The year commands work on unless told otherwise
+pub fn latest_year() -> u32 {
+    YEARS.last().map(|year| year.number).unwrap()
+}
+
+// Every day of year the runner knows about, in order, or none for a year it doesn't
+pub fn registry(year: u32) -> Vec<Day> {
+    find_year(year).map(|year| year.days()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_registry_is_in_order() {
+        assert!(YEARS.windows(2).all(|pair| pair[0].number < pair[1].number));
+
+        for year in YEARS {
+            let days = year.days();
+            let numbers: Vec<u32> = days.iter().map(|day| day.number).collect();
+
+            assert_eq!(1, numbers[0]);
+            assert!(numbers.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(days.iter().all(|day| day.year == year.number));
+        }
+    }
+
+    #[test]
+    fn test_find_year() {
+        assert_eq!(2020, find_year(2020).unwrap().number);
+        assert_eq!("there are no puzzles for 2019, only for 2020", find_year(2019).err().unwrap());
+        assert!(registry(2019).is_empty());
+    }
+}
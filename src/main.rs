@@ -1,62 +1,327 @@
-#[macro_use] extern crate lazy_static;
-extern crate regex;
-
-use core::fmt::Debug;
+use aoc::answers::{self, ExpectedAnswers, Verdict};
+use aoc::bench;
+use aoc::client::{self, Client, Fetched};
+use aoc::examples;
+use aoc::error::AocError;
+use aoc::generate;
+use aoc::input::InputSource;
+use aoc::picture;
+use aoc::report::Report;
+use aoc::runner::{self, DayResult};
+use aoc::scaffold;
+use aoc::solution::{Answer, Day};
+use aoc::find_year;
+use aoc::submit;
+use aoc::watch;
+use cli::{Command, Options};
 use std::env;
+use std::path::Path;
+use std::process;
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09; 
-mod day10; 
-mod day11; 
-mod day12; 
-mod day13; 
-mod day14; 
-mod day15; 
-mod day16; 
-mod day17; 
-mod day18; 
-mod day19; 
-mod day20; 
+mod cli;
 
 fn main() {
     env_logger::init();
 
-    let mut args = env::args();
-    args.next();
-
-    let day_or_all = &args.next().unwrap_or("all".to_string());
-
-    run_day("day1", &day01::run, day_or_all);
-    run_day("day2", &day02::run, day_or_all);
-    run_day("day3", &day03::run, day_or_all);
-    run_day("day4", &day04::run, day_or_all);
-    run_day("day5", &day05::run, day_or_all);
-    run_day("day6", &day06::run, day_or_all);
-    run_day("day7", &day07::run, day_or_all);
-    run_day("day8", &day08::run, day_or_all);
-    run_day("day9", &day09::run, day_or_all);
-    run_day("day10", &day10::run, day_or_all);
-    run_day("day11", &day11::run, day_or_all);
-    run_day("day12", &day12::run, day_or_all);
-    run_day("day13", &day13::run, day_or_all);
-    run_day("day14", &day14::run, day_or_all);
-    run_day("day15", &day15::run, day_or_all);
-    run_day("day16", &day16::run, day_or_all);
-    run_day("day17", &day17::run, day_or_all);
-    run_day("day18", &day18::run, day_or_all);
-    run_day("day19", &day19::run, day_or_all);
-    run_day("day20", &day20::run, day_or_all);
-}
-
-fn run_day<T>(name: &str, f: & dyn Fn() -> T, day_or_all: &str) where T: Debug {
-    if day_or_all == "all" || day_or_all == name {
-        println!("{} = {:?}", name, f());
+    let command = Command::parse(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, cli::USAGE);
+        process::exit(2);
+    });
+
+    match command {
+        Command::List(year) => list_days(year),
+        Command::Run(options) => run_days(&options),
+        Command::Bench(options) => {
+            bench::print_header();
+            for_each_day(&options, |day, input| {
+                bench::print_result(day, &bench::bench_day(day, input, options.iterations));
+            });
+        },
+        Command::Verify(options) => verify_days(&options),
+        Command::Fetch(options) => fetch_inputs(&options),
+        Command::Submit(options, part) => submit_answer(&options, part),
+        Command::Watch(options) => {
+            let day = &selected_days(&options)[0];
+            let input = match &options.input {
+                Some(InputSource::File(path)) => path.clone(),
+                _ => day.input_path().into()
+            };
+            watch::watch(day, &input, options.timeout)
+        },
+        Command::Draw(options, part, path) => draw(&options, part, &path),
+        Command::New(year, day, title) => new_day(year, day, &title),
+        Command::Gen(year, day, size, seed) => match generate::generate(year, day, size, seed) {
+            Ok(input) => print!("{}", input),
+            Err(err) => {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn list_days(year: u32) {
+    let year = find_year(year).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(2);
+    });
+
+    for day in year.days() {
+        let names: Vec<&str> = examples::for_day(year.number, day.number).map(|example| example.name).collect();
+        if names.is_empty() {
+            println!("{:<6} {}", day.name(), day.title);
+        } else {
+            println!("{:<6} {:<26} examples: {}", day.name(), day.title, names.join(", "));
+        }
+    }
+}
+
+fn selected_days(options: &Options) -> Vec<Day> {
+    let year = find_year(options.year).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(2);
+    });
+
+    let days: Vec<Day> = year
+        .days()
+        .into_iter()
+        .filter(|day| options.includes(&day.name()))
+        .collect();
+
+    if days.is_empty() {
+        eprintln!("unknown day {}, see aoc list", options.day_or_all);
+        process::exit(2);
+    }
+
+    days
+}
+
+fn read_input(day: &Day, options: &Options) -> Result<String, String> {
+    if let Some(name) = &options.example {
+        return examples::find(day.year, day.number, name)
+            .map(|example| example.input.to_string())
+            .ok_or_else(|| format!("no example called {}, see aoc list", name));
+    }
+
+    options
+        .input
+        .clone()
+        .unwrap_or_else(|| InputSource::File(day.input_path().into()))
+        .read()
+        .map_err(|err| err.to_string())
+}
+
+// Read the input for each day selected by the options and hand it to f
+fn for_each_day<F: FnMut(&Day, &str)>(options: &Options, mut f: F) {
+    let mut failed = false;
+
+    for day in selected_days(options) {
+        match read_input(&day, options) {
+            Ok(input) => f(&day, &input),
+            Err(err) => {
+                eprintln!("{}: {}", day.name(), err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+// Read the input for each day selected by the options and run it, on up to
+// options.jobs threads and stopping any day that runs past options.timeout. f is
+// called with each day's result in day order.
+fn run_selected_days<F: FnMut(&Day, Result<DayResult, String>)>(options: &Options, f: F) {
+    let days = selected_days(options);
+
+    runner::run_in_order(
+        &days,
+        options.jobs,
+        |day| read_input(day, options).map(|input| runner::run_day_with_timeout(day, &input, options.timeout)),
+        f);
+}
+
+fn run_days(options: &Options) {
+    let mut report = Report::new(options.format, options.time, options.memory);
+    let mut failed = false;
+
+    report.start();
+
+    run_selected_days(options, |day, result| {
+        let result = result.unwrap_or_else(|err| DayResult::failed(AocError::new(err).in_day(day.number)));
+
+        failed |= result.parts().iter().any(|(_, part)| part.answer.is_err());
+        report.add(day, &result);
+    });
+
+    report.finish();
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+// The answers to check against: those given for the example, if one was chosen, or
+// else the known answers file
+fn expected_answers(options: &Options) -> ExpectedAnswers {
+    let example = options.example.as_ref().and_then(|name| {
+        selected_days(options)
+            .iter()
+            .find_map(|day| examples::find(day.year, day.number, name).map(|example| (day.id(), example)))
+    });
+
+    match example {
+        Some((day, example)) => {
+            let mut expected = ExpectedAnswers::default();
+            for (part, answer) in [(1, example.part1), (2, example.part2)].iter() {
+                if let Some(answer) = answer {
+                    expected.insert(&day, *part, answer);
+                }
+            }
+            expected
+        },
+        None => ExpectedAnswers::load(&options.answers).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(2);
+        })
+    }
+}
+
+fn verify_days(options: &Options) {
+    let expected = expected_answers(options);
+
+    let mut wrong = 0;
+    let mut unknown = 0;
+    let mut correct = 0;
+    let mut unreadable = false;
+
+    run_selected_days(options, |day, result| {
+        let result = match result {
+            Ok(result) => result,
+            Err(err) => {
+                eprintln!("{}: {}", day.name(), err);
+                unreadable = true;
+                return;
+            }
+        };
+
+        for (part, part_result) in result.parts().iter() {
+            let actual = match &part_result.answer {
+                Ok(actual) => actual,
+                Err(err) => {
+                    wrong += 1;
+                    println!("{:<6} part{} FAILED   {}", day.name(), part, err);
+                    continue;
+                }
+            };
+
+            match answers::check(expected.get(&day.id(), *part), actual) {
+                Verdict::Correct => {
+                    correct += 1;
+                    println!("{:<6} part{} ok       {}", day.name(), part, actual);
+                },
+                Verdict::Wrong(expected) => {
+                    wrong += 1;
+                    println!("{:<6} part{} WRONG    expected {}, got {}", day.name(), part, expected, actual);
+                },
+                Verdict::Unknown => {
+                    unknown += 1;
+                    println!("{:<6} part{} unknown  {}", day.name(), part, actual);
+                }
+            }
+        }
+    });
+
+    println!("{} correct, {} wrong, {} unknown", correct, wrong, unknown);
+
+    if wrong > 0 || unreadable {
+        process::exit(1);
+    }
+}
+
+fn fetch_inputs(options: &Options) {
+    let mut client = Client::new(&options.base_url, options.year, client::load_session());
+    let mut failed = false;
+
+    for day in selected_days(options) {
+        let path = day.input_path();
+        match client.fetch_input(day.number, path.as_ref()) {
+            Ok(Fetched::Cached) => println!("{:<6} cached      {}", day.name(), path),
+            Ok(Fetched::Downloaded) => println!("{:<6} downloaded  {}", day.name(), path),
+            Err(err) => {
+                eprintln!("{}: {}", day.name(), err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn submit_answer(options: &Options, part: u32) {
+    let day = &selected_days(options)[0];
+
+    let fail = |err: &dyn std::fmt::Display| -> ! {
+        eprintln!("{}: {}", day.name(), err);
+        process::exit(1);
+    };
+
+    let input = read_input(day, options).unwrap_or_else(|err| fail(&err));
+    let result = runner::run_day_with_timeout(day, &input, options.timeout);
+    let answer = match &result.parts()[part as usize - 1].1.answer {
+        Ok(Answer::Unsolved) => fail(&format!("part {} isn't solved yet", part)),
+        Ok(answer) => answer.to_string(),
+        Err(err) => fail(err)
+    };
+
+    let mut client = Client::new(&options.base_url, day.year, client::load_session());
+
+    match submit::submit(&mut client, &options.history, day.number, part, &answer) {
+        Ok(outcome) => {
+            println!("{} part{} {}: {}", day.name(), part, answer, outcome);
+            if outcome != submit::Outcome::Correct {
+                process::exit(1);
+            }
+        },
+        Err(err) => fail(&err)
+    }
+}
+
+fn draw(options: &Options, part: u32, path: &Path) {
+    let day = &selected_days(options)[0];
+
+    let fail = |err: &dyn std::fmt::Display| -> ! {
+        eprintln!("{}: {}", day.name(), err);
+        process::exit(1);
+    };
+
+    let input = read_input(day, options).unwrap_or_else(|err| fail(&err));
+    let solution = day.parse(&input).unwrap_or_else(|err| fail(&err));
+    let grid = match solution.picture(part) {
+        Ok(Some(grid)) => grid,
+        Ok(None) => fail(&"there is no picture for this day"),
+        Err(err) => fail(&err)
+    };
+
+    let palette = options.palette();
+    picture::save(path, &grid, options.scale, |c| palette.colour(c)).unwrap_or_else(|err| fail(&err));
+    println!("wrote {} ({}x{} cells)", path.display(), grid.width(), grid.height());
+}
+
+fn new_day(year: u32, day: u32, title: &str) {
+    match scaffold::scaffold(".".as_ref(), year, day, title) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path);
+            }
+        },
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
     }
 }
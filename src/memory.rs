@@ -0,0 +1,197 @@
+// Heap usage of the solvers. Built with the alloc-stats feature, the crate installs a
+// global allocator that counts every allocation against the thread making it, and
+// measure reports what a closure allocated. Counting per thread keeps days running at
+// the same time on other threads out of each other's figures. Without the feature
+// nothing is counted and measure reports nothing.
+use std::fmt;
+
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+// What one step allocated: the total bytes asked for, the number of allocations and
+// the most it had in use at once, beyond what was in use before it started
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AllocStats {
+    pub bytes: u64,
+    pub count: u64,
+    pub peak: u64
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} in {} allocations, peak {}", format_bytes(self.bytes), self.count, format_bytes(self.peak))
+    }
+}
+
+pub fn measure<R, F: FnOnce() -> R>(f: F) -> (R, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let start = counting::start();
+        let result = f();
+        (result, Some(counting::stop(start)))
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1}{}", size, UNITS[unit])
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use crate::memory::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    #[derive(Clone, Copy)]
+    struct Counts {
+        bytes: u64,
+        count: u64,
+        // Memory freed here may have been allocated on another thread, so this can
+        // go below zero
+        live: i64,
+        peak: i64
+    }
+
+    thread_local! {
+        static COUNTS: Cell<Counts> = const { Cell::new(Counts { bytes: 0, count: 0, live: 0, peak: 0 }) };
+    }
+
+    // Counting must not allocate, and may happen while the thread is being torn down
+    // and its locals are gone, when the allocation just isn't counted
+    fn update<F: FnOnce(&mut Counts)>(f: F) {
+        let _ = COUNTS.try_with(|counts| {
+            let mut c = counts.get();
+            f(&mut c);
+            c.peak = c.peak.max(c.live);
+            counts.set(c);
+        });
+    }
+
+    fn allocated(size: usize) {
+        update(|c| {
+            c.bytes += size as u64;
+            c.count += 1;
+            c.live += size as i64;
+        });
+    }
+
+    fn freed(size: usize) {
+        update(|c| c.live -= size as i64);
+    }
+
+    pub struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            freed(layout.size());
+        }
+
+        // Counted as a new allocation of the new size in place of the old one
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    // The counts when a measurement started. The peak is restarted from what is in use
+    // now and put back afterwards, so measurements can nest.
+    pub struct Start {
+        bytes: u64,
+        count: u64,
+        live: i64,
+        peak: i64
+    }
+
+    pub fn start() -> Start {
+        let mut start = None;
+        update(|c| {
+            start = Some(Start { bytes: c.bytes, count: c.count, live: c.live, peak: c.peak });
+            c.peak = c.live;
+        });
+        start.unwrap_or(Start { bytes: 0, count: 0, live: 0, peak: 0 })
+    }
+
+    pub fn stop(start: Start) -> AllocStats {
+        let mut stats = AllocStats::default();
+        update(|c| {
+            stats = AllocStats {
+                bytes: c.bytes - start.bytes,
+                count: c.count - start.count,
+                peak: (c.peak - start.live).max(0) as u64
+            };
+            c.peak = c.peak.max(start.peak);
+        });
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memory::*;
+
+    #[test]
+    fn test_measure() {
+        let (total, stats) = measure(|| {
+            let small: Vec<u64> = (0..10).collect();
+            let large = vec![0u8; 1 << 20];
+            drop(large);
+            small.iter().sum::<u64>()
+        });
+
+        assert_eq!(45, total);
+        assert_eq!(ENABLED, stats.is_some());
+        if let Some(stats) = stats {
+            assert!(stats.count >= 2);
+            assert!(stats.bytes >= 80 + (1 << 20));
+            assert!(stats.peak >= 1 << 20 && stats.peak < 2 << 20);
+        }
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("512B", format_bytes(512));
+        assert_eq!("1.5KiB", format_bytes(1536));
+        assert_eq!("3.0MiB", format_bytes(3 << 20));
+    }
+}
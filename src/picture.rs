@@ -0,0 +1,203 @@
+// Pictures of grids as Netpbm images, which nearly every image viewer opens and which
+// take no more than a header and the pixels to write. A colour map picks each cell's
+// colour; each cell becomes a scale by scale square of pixels. Bitmaps (.pbm) are
+// black where a colour is dark and greymaps (.pgm) use its brightness, so one colour
+// map does for all three formats.
+use crate::grid::Grid;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8
+}
+
+impl Colour {
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Colour {
+        Colour { r, g, b }
+    }
+
+    // ITU-R BT.601 luma
+    pub fn grey(&self) -> u8 {
+        ((299 * self.r as u32 + 587 * self.g as u32 + 114 * self.b as u32) / 1000) as u8
+    }
+}
+
+pub const BLACK: Colour = Colour::rgb(0, 0, 0);
+pub const WHITE: Colour = Colour::rgb(255, 255, 255);
+
+// Six hex digits as in HTML, with or without the #
+impl FromStr for Colour {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Colour, String> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let channel = |i: usize| hex.get(i..i + 2).and_then(|c| u8::from_str_radix(c, 16).ok());
+
+        match (hex.len(), channel(0), channel(2), channel(4)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Colour::rgb(r, g, b)),
+            _ => Err(format!("expected a colour like ff8000, found {:?}", s))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Bitmap,
+    Greymap,
+    Pixmap
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Result<Format, String> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("pbm") => Ok(Format::Bitmap),
+            Some("pgm") => Ok(Format::Greymap),
+            Some("ppm") => Ok(Format::Pixmap),
+            _ => Err(format!("{} should end in .pbm, .pgm or .ppm", path.display()))
+        }
+    }
+}
+
+// The binary form of each format: P4 packs eight pixels to a byte, with 1 for black
+pub fn encode<T, F: Fn(&T) -> Colour>(grid: &Grid<T>, format: Format, scale: usize, colour: F) -> Vec<u8> {
+    let scale = scale.max(1);
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+
+    let mut image = match format {
+        Format::Bitmap => format!("P4\n{} {}\n", width, height),
+        Format::Greymap => format!("P5\n{} {}\n255\n", width, height),
+        Format::Pixmap => format!("P6\n{} {}\n255\n", width, height)
+    }.into_bytes();
+
+    for y in 0..grid.height() {
+        let row: Vec<Colour> = grid.row(y).iter().flat_map(|cell| std::iter::repeat_n(colour(cell), scale)).collect();
+
+        let mut bytes = Vec::new();
+        match format {
+            Format::Bitmap => {
+                for pixels in row.chunks(8) {
+                    let byte = pixels
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, pixel)| pixel.grey() < 128)
+                        .fold(0u8, |byte, (i, _)| byte | 0x80 >> i);
+                    bytes.push(byte);
+                }
+            },
+            Format::Greymap => bytes.extend(row.iter().map(Colour::grey)),
+            Format::Pixmap => bytes.extend(row.iter().flat_map(|pixel| vec![pixel.r, pixel.g, pixel.b]))
+        }
+
+        for _ in 0..scale {
+            image.extend_from_slice(&bytes);
+        }
+    }
+
+    image
+}
+
+// Write grid to path, in the format its extension names
+pub fn save<T, F: Fn(&T) -> Colour>(path: &Path, grid: &Grid<T>, scale: usize, colour: F) -> Result<(), String> {
+    let format = Format::from_path(path)?;
+    fs::write(path, encode(grid, format, scale, colour)).map_err(|err| format!("cannot write {}: {}", path.display(), err))
+}
+
+// Colours for grids of characters, as the days draw their pictures. Cells without a
+// colour of their own are drawn in grey.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    colours: HashMap<char, Colour>
+}
+
+impl Default for Palette {
+    fn default() -> Palette {
+        Palette {
+            colours: [
+                ('.', WHITE),
+                ('#', BLACK),
+                ('L', Colour::rgb(0x4c, 0xaf, 0x50)),
+                ('O', Colour::rgb(0xe5, 0x39, 0x35))
+            ].iter().copied().collect()
+        }
+    }
+}
+
+impl Palette {
+    pub fn colour(&self, c: &char) -> Colour {
+        self.colours.get(c).copied().unwrap_or(Colour::rgb(0x80, 0x80, 0x80))
+    }
+
+    pub fn set(&mut self, c: char, colour: Colour) {
+        self.colours.insert(c, colour);
+    }
+
+    // Changes to the colours given as a comma separated list like "#=000080,O=ff0000"
+    pub fn parse_changes(changes: &str) -> Result<Vec<(char, Colour)>, String> {
+        changes
+            .split(',')
+            .map(|change| {
+                let mut chars = change.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), Some('=')) => Ok((c, chars.as_str().parse()?)),
+                    _ => Err(format!("expected a character and a colour like O=ff0000, found {:?}", change))
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::picture::*;
+
+    fn bools(input: &str) -> Grid<bool> {
+        Grid::parse(input, |c| Some(c == '#')).unwrap()
+    }
+
+    fn black_and_white(cell: &bool) -> Colour {
+        if *cell { BLACK } else { WHITE }
+    }
+
+    #[test]
+    fn test_encode_bitmap() {
+        let grid = bools("#.#\n.#.");
+
+        assert_eq!(b"P4\n3 2\n\xa0\x40".to_vec(), encode(&grid, Format::Bitmap, 1, black_and_white));
+        assert_eq!(b"P4\n6 4\n\xcc\xcc\x30\x30".to_vec(), encode(&grid, Format::Bitmap, 2, black_and_white));
+    }
+
+    #[test]
+    fn test_encode_greymap_and_pixmap() {
+        let grid = bools("#.");
+        let red = |cell: &bool| if *cell { Colour::rgb(255, 0, 0) } else { WHITE };
+
+        assert_eq!(b"P5\n2 1\n255\n\x4c\xff".to_vec(), encode(&grid, Format::Greymap, 1, red));
+        assert_eq!(b"P6\n2 1\n255\n\xff\x00\x00\xff\xff\xff".to_vec(), encode(&grid, Format::Pixmap, 1, red));
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(Ok(Format::Pixmap), Format::from_path("day20.ppm".as_ref()));
+        assert!(Format::from_path("day20.png".as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_palette() {
+        let changes = Palette::parse_changes("O=00ff00,#=#000080").unwrap();
+        let mut palette = Palette::default();
+        for (c, colour) in changes {
+            palette.set(c, colour);
+        }
+
+        assert_eq!(Colour::rgb(0, 255, 0), palette.colour(&'O'));
+        assert_eq!(Colour::rgb(0, 0, 128), palette.colour(&'#'));
+        assert_eq!(WHITE, palette.colour(&'.'));
+        assert!(Palette::parse_changes("O=red").is_err());
+        assert!(Palette::parse_changes("O").is_err());
+    }
+}
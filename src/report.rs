@@ -0,0 +1,223 @@
+use crate::error::AocError;
+use crate::memory::AllocStats;
+use crate::runner::{format_duration, DayResult};
+use crate::solution::{Answer, Day};
+use std::str::FromStr;
+
+// How the run command prints its results
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format {}, expected text, json or csv", s))
+        }
+    }
+}
+
+// Writes one record per day and part. JSON output is a single array so the whole of
+// stdout parses as one document; CSV output starts with a header row. With memory set,
+// each record also has what the step allocated.
+pub struct Report {
+    format: Format,
+    time: bool,
+    memory: bool,
+    records: usize
+}
+
+impl Report {
+    pub fn new(format: Format, time: bool, memory: bool) -> Report {
+        Report {
+            format,
+            time,
+            memory,
+            records: 0
+        }
+    }
+
+    pub fn start(&self) {
+        match self.format {
+            Format::Text => (),
+            Format::Json => println!("["),
+            Format::Csv if self.memory => println!("year,day,part,answer,elapsed_ms,error,allocated_bytes,allocations,peak_bytes"),
+            Format::Csv => println!("year,day,part,answer,elapsed_ms,error")
+        }
+    }
+
+    pub fn add(&mut self, day: &Day, result: &DayResult) {
+        match self.format {
+            Format::Text => self.add_text(day, result),
+            Format::Json | Format::Csv => {
+                for (part, part_result) in result.parts().iter() {
+                    let answer = part_result.answer.as_ref().map(|a| a.to_string()).map_err(|e| e.to_string());
+                    let elapsed_ms = part_result.elapsed.as_secs_f64() * 1000.0;
+                    // A part that never ran allocated nothing
+                    let memory = Some(part_result.memory.unwrap_or_default()).filter(|_| self.memory);
+                    let record = match self.format {
+                        Format::Json => json_record(day.year, day.number, *part, &answer, elapsed_ms, memory),
+                        _ => csv_record(day.year, day.number, *part, &answer, elapsed_ms, memory)
+                    };
+                    if self.format == Format::Json && self.records > 0 {
+                        println!(",");
+                    }
+                    print!("{}", record);
+                    if self.format == Format::Csv {
+                        println!();
+                    }
+                    self.records += 1;
+                }
+            }
+        }
+    }
+
+    pub fn finish(&self) {
+        if self.format == Format::Json {
+            if self.records > 0 {
+                println!();
+            }
+            println!("]");
+        }
+    }
+
+    fn add_text(&self, day: &Day, result: &DayResult) {
+        fn show(answer: &Result<Answer, AocError>) -> String {
+            match answer {
+                Ok(answer) => answer.to_string(),
+                Err(_) => "error".to_string()
+            }
+        }
+
+        println!("{} = ({}, {})", day.name(), show(&result.part1.answer), show(&result.part2.answer));
+
+        match (&result.part1.answer, &result.part2.answer) {
+            (Err(err1), Err(err2)) if err1 == err2 => println!("    failed: {}", err1),
+            _ => {
+                for (part, part_result) in result.parts().iter() {
+                    if let Err(err) = &part_result.answer {
+                        println!("    part{} failed: {}", part, err);
+                    }
+                }
+            }
+        }
+
+        if self.time {
+            println!(
+                "    parse {}, part1 {}, part2 {}",
+                format_duration(result.parse_elapsed),
+                format_duration(result.part1.elapsed),
+                format_duration(result.part2.elapsed));
+        }
+
+        if self.memory {
+            let steps = [("parse", result.parse_memory), ("part1", result.part1.memory), ("part2", result.part2.memory)];
+            for (step, memory) in steps.iter() {
+                if let Some(memory) = memory {
+                    println!("    {} {}", step, memory);
+                }
+            }
+        }
+    }
+}
+
+fn json_record(
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &Result<String, String>,
+    elapsed_ms: f64,
+    memory: Option<AllocStats>) -> String {
+
+    let (answer, error) = match answer {
+        Ok(answer) => (json_string(answer), "null".to_string()),
+        Err(error) => ("null".to_string(), json_string(error))
+    };
+    let memory = memory.map_or(String::new(), |m| {
+        format!(", \"allocated_bytes\": {}, \"allocations\": {}, \"peak_bytes\": {}", m.bytes, m.count, m.peak)
+    });
+
+    format!(
+        "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ms\": {:.3}, \"error\": {}{}}}",
+        year, day, part, answer, elapsed_ms, error, memory)
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c)
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn csv_record(
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &Result<String, String>,
+    elapsed_ms: f64,
+    memory: Option<AllocStats>) -> String {
+
+    let (answer, error) = match answer {
+        Ok(answer) => (csv_field(answer), String::new()),
+        Err(error) => (String::new(), csv_field(error))
+    };
+    let memory = memory.map_or(String::new(), |m| format!(",{},{},{}", m.bytes, m.count, m.peak));
+
+    format!("{},{},{},{},{:.3},{}{}", year, day, part, answer, elapsed_ms, error, memory)
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+
+    #[test]
+    fn test_json_record() {
+        assert_eq!(
+            r#"  {"year": 2020, "day": 7, "part": 1, "answer": "372", "elapsed_ms": 1.500, "error": null}"#,
+            json_record(2020, 7, 1, &Ok("372".to_string()), 1.5, None));
+        assert_eq!(
+            r#"  {"year": 2020, "day": 7, "part": 2, "answer": null, "elapsed_ms": 0.000, "error": "bad \"input\"\n"}"#,
+            json_record(2020, 7, 2, &Err("bad \"input\"\n".to_string()), 0.0, None));
+        assert_eq!(
+            r#"  {"year": 2020, "day": 14, "part": 2, "answer": "9", "elapsed_ms": 2.000, "error": null, "allocated_bytes": 4096, "allocations": 3, "peak_bytes": 2048}"#,
+            json_record(2020, 14, 2, &Ok("9".to_string()), 2.0, Some(AllocStats { bytes: 4096, count: 3, peak: 2048 })));
+    }
+
+    #[test]
+    fn test_csv_record() {
+        assert_eq!("2020,7,1,372,1.500,", csv_record(2020, 7, 1, &Ok("372".to_string()), 1.5, None));
+        assert_eq!(
+            "2020,7,2,,0.000,\"expected a, b or \"\"c\"\"\"",
+            csv_record(2020, 7, 2, &Err("expected a, b or \"c\"".to_string()), 0.0, None));
+        assert_eq!(
+            "2020,14,2,9,2.000,,4096,3,2048",
+            csv_record(2020, 14, 2, &Ok("9".to_string()), 2.0, Some(AllocStats { bytes: 4096, count: 3, peak: 2048 })));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,249 @@
+use crate::cancel;
+use crate::error::AocError;
+use crate::memory::{self, AllocStats};
+use crate::solution::{Answer, Day, Solution};
+use std::any::Any;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub answer: Result<Answer, AocError>,
+    pub elapsed: Duration,
+    // Only counted when built with the alloc-stats feature
+    pub memory: Option<AllocStats>
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub parse_elapsed: Duration,
+    pub parse_memory: Option<AllocStats>,
+    pub part1: PartResult,
+    pub part2: PartResult
+}
+
+impl DayResult {
+    // A day that could not be run at all, e.g. because its input could not be read
+    pub fn failed(error: AocError) -> DayResult {
+        DayResult {
+            parse_elapsed: Duration::default(),
+            parse_memory: None,
+            part1: PartResult { answer: Err(error.clone()), elapsed: Duration::default(), memory: None },
+            part2: PartResult { answer: Err(error), elapsed: Duration::default(), memory: None }
+        }
+    }
+
+    pub fn parts(&self) -> [(u32, &PartResult); 2] {
+        [(1, &self.part1), (2, &self.part2)]
+    }
+}
+
+// Parse the input and solve both parts, timing each step separately and counting what
+// it allocates if that is enabled. The parsed input stays allocated while the parts
+// run, so it counts towards parsing and not their peaks. Errors are
+// tagged with the day. A panic while parsing or solving is caught and reported as
+// that step's error so that one broken day doesn't abort the rest of the run.
+pub fn run_day(day: &Day, input: &str) -> DayResult {
+    let start = Instant::now();
+    let (solution, parse_memory) = memory::measure(|| {
+        panic::catch_unwind(|| day.parse(input)).unwrap_or_else(|payload| Err(panicked(payload)))
+    });
+    let parse_elapsed = start.elapsed();
+
+    let solution = match solution {
+        Ok(solution) => solution,
+        Err(err) => {
+            let mut result = DayResult::failed(err.in_day(day.number));
+            result.parse_elapsed = parse_elapsed;
+            result.parse_memory = parse_memory;
+            return result;
+        }
+    };
+
+    DayResult {
+        parse_elapsed,
+        parse_memory,
+        part1: run_part(day, &*solution, |s| s.part1()),
+        part2: run_part(day, &*solution, |s| s.part2())
+    }
+}
+
+// As run_day, but parts still running once timeout has passed stop with a timed out
+// error, as do parts that finish after it. The remaining parts fail the same way
+// straight away.
+pub fn run_day_with_timeout(day: &Day, input: &str, timeout: Option<Duration>) -> DayResult {
+    cancel::with_timeout(timeout, || run_day(day, input))
+}
+
+fn run_part(
+    day: &Day,
+    solution: &dyn Solution,
+    part: fn(&dyn Solution) -> Result<Answer, AocError>) -> PartResult {
+
+    let start = Instant::now();
+    let (answer, memory) = memory::measure(|| {
+        cancel::check().and_then(|_| {
+            panic::catch_unwind(AssertUnwindSafe(|| part(solution)))
+                .unwrap_or_else(|payload| Err(panicked(payload)))
+                .and_then(|answer| cancel::check().map(|_| answer))
+        })
+    });
+
+    PartResult {
+        answer: answer.map_err(|err| err.in_day(day.number)),
+        elapsed: start.elapsed(),
+        memory
+    }
+}
+
+fn panicked(payload: Box<dyn Any + Send>) -> AocError {
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    };
+
+    AocError::new(format!("panicked: {}", message))
+}
+
+// Apply work to every item on up to jobs worker threads. done is called on the calling
+// thread with each item and its result in the order of the items, as soon as that
+// item and all those before it have finished.
+pub fn run_in_order<T, R, W, D>(items: &[T], jobs: usize, work: W, mut done: D)
+where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    D: FnMut(&T, R) {
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+            scope.spawn(move || {
+                loop {
+                    let i = next.fetch_add(1, Ordering::SeqCst);
+                    if i >= items.len() || sender.send((i, work(&items[i]))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished: Vec<Option<R>> = items.iter().map(|_| None).collect();
+        let mut reported = 0;
+
+        for (i, result) in receiver {
+            finished[i] = Some(result);
+            while let Some(result) = finished.get_mut(reported).and_then(Option::take) {
+                done(&items[reported], result);
+                reported += 1;
+            }
+        }
+    });
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let micros = d.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.3}ms", micros / 1_000.0)
+    } else {
+        format!("{:.3}s", micros / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::*;
+    use crate::error::AocError;
+    use crate::solution::*;
+    use std::time::Duration;
+
+    struct Broken {}
+
+    impl Solution for Broken {
+        fn parse(_input: &str) -> Result<Broken, AocError> {
+            Ok(Broken {})
+        }
+
+        fn part1(&self) -> Result<Answer, AocError> {
+            Err(AocError::new("part 1 is broken"))
+        }
+
+        fn part2(&self) -> Result<Answer, AocError> {
+            panic!("part 2 is broken")
+        }
+    }
+
+    #[test]
+    fn test_run_day_reports_errors_and_panics() {
+        let day = Day::new::<Broken>(2020, 99, "Broken");
+
+        let result = run_day(&day, "");
+
+        assert_eq!("day99: part 1 is broken", result.part1.answer.unwrap_err().to_string());
+        assert_eq!("day99: panicked: part 2 is broken", result.part2.answer.unwrap_err().to_string());
+    }
+
+    struct Slow {}
+
+    // Never checks for a timeout
+    impl Solution for Slow {
+        fn parse(_input: &str) -> Result<Slow, AocError> {
+            Ok(Slow {})
+        }
+
+        fn part1(&self) -> Result<Answer, AocError> {
+            thread::sleep(Duration::from_millis(50));
+            Ok(Answer::Number(1))
+        }
+
+        fn part2(&self) -> Result<Answer, AocError> {
+            Ok(Answer::Number(2))
+        }
+    }
+
+    #[test]
+    fn test_late_answers_are_timed_out() {
+        let day = Day::new::<Slow>(2020, 99, "Slow");
+
+        let result = run_day_with_timeout(&day, "", Some(Duration::from_millis(10)));
+
+        assert_eq!("day99: timed out after 10.000ms", result.part1.answer.unwrap_err().to_string());
+        assert_eq!("day99: timed out after 10.000ms", result.part2.answer.unwrap_err().to_string());
+        assert_eq!(Ok(Answer::Number(1)), run_day_with_timeout(&day, "", None).part1.answer);
+    }
+
+    #[test]
+    fn test_run_in_order_reports_in_item_order() {
+        let items: Vec<u64> = (1..=8).collect();
+        let mut reported = Vec::new();
+
+        // Later items finish first
+        run_in_order(&items, 4, |n| {
+            thread::sleep(Duration::from_millis(40 - n * 5));
+            n * 10
+        }, |n, result| reported.push((*n, result)));
+
+        assert_eq!(items.iter().map(|n| (*n, n * 10)).collect::<Vec<_>>(), reported);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("12.0µs", format_duration(Duration::from_micros(12)));
+        assert_eq!("1.500ms", format_duration(Duration::from_micros(1500)));
+        assert_eq!("2.250s", format_duration(Duration::from_millis(2250)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,326 @@
+use std::fs;
+use std::path::Path;
+
+// The skeleton of a new day, in the same shape as the existing ones. YEAR is replaced
+// by the year and DAY by the zero padded day number.
+const TEMPLATE: &str = r#"use crate::error::{lines, AocError};
+use crate::solution::{Answer, Solution};
+
+pub struct Puzzle {
+    pub lines: Vec<String>
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &str) -> Result<Puzzle, AocError> {
+        Ok(Puzzle {
+            lines: lines(input)
+                .filter(|line| !line.text.trim().is_empty())
+                .map(|line| line.text.trim().to_string())
+                .collect()
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yearYEAR::dayDAY::*;
+
+    // Paste the example from the puzzle description here
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn test_example() {
+        let puzzle = Puzzle::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Ok(Answer::Unsolved), puzzle.part1());
+        assert_eq!(Ok(Answer::Unsolved), puzzle.part2());
+    }
+}
+"#;
+
+// The module of a year with no days yet, and its empty lists of examples and input
+// generators. NNNN is replaced by the year.
+const YEAR_TEMPLATE: &str = r#"// Advent of Code NNNN
+use crate::solution::Day;
+
+pub mod examples;
+pub mod generate;
+
+pub const YEAR: u32 = NNNN;
+
+// Every day of the year the runner knows about, in order. Adding a day means adding
+// one line here, which aoc new dayN does along with the module.
+pub fn registry() -> Vec<Day> {
+    vec![
+    ]
+}
+"#;
+
+const EXAMPLES_TEMPLATE: &str = r#"// The worked examples from the NNNN puzzle descriptions
+use crate::examples::Example;
+
+pub const EXAMPLES: &[Example] = &[
+];
+"#;
+
+const GENERATORS_TEMPLATE: &str = r#"// Input generators for the NNNN puzzles, see crate::generate
+use crate::generate::Generator;
+
+pub const GENERATORS: &[(u32, usize, Generator)] = &[
+];
+"#;
+
+pub fn module_source(year: u32, day: u32) -> String {
+    TEMPLATE.replace("YEAR", &year.to_string()).replace("DAY", &format!("{:02}", day))
+}
+
+// Add the module declaration and registry entry for day to the source of a year's
+// mod.rs, keeping both in day order
+pub fn register(year_module: &str, day: u32, title: &str) -> Result<String, String> {
+    let module = format!("day{:02}", day);
+    let declaration = format!("pub mod {};", module);
+    let entry = format!("        Day::new::<{}::Puzzle>(YEAR, {}, {:?}),", module, day, title);
+
+    let mut lines: Vec<String> = year_module.lines().map(|line| line.to_string()).collect();
+
+    if lines.iter().any(|line| line.trim() == declaration) {
+        return Err(format!("{} is already declared", module));
+    }
+
+    // The first day of a year goes after the other modules and at the start of the
+    // registry
+    if !lines.iter().any(|line| line.starts_with("pub mod day")) {
+        let after_modules = lines.iter().rposition(|line| line.starts_with("pub mod ")).map_or(0, |i| i + 1);
+        lines.insert(after_modules, String::new());
+        lines.insert(after_modules + 1, declaration);
+        let registry = lines
+            .iter()
+            .position(|line| line.trim() == "vec![")
+            .ok_or_else(|| "can't find the registry".to_string())?;
+        lines.insert(registry + 1, entry);
+        return Ok(lines.join("\n") + "\n");
+    }
+
+    insert_in_order(&mut lines, &declaration, day, |line| {
+        line.strip_prefix("pub mod day")?.strip_suffix(';')?.parse().ok()
+    })?;
+
+    insert_in_order(&mut lines, &entry, day, |line| {
+        line.trim().strip_prefix("Day::new::<day")?.get(..2)?.parse().ok()
+    })?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Add the module declaration and YEARS entry for a new year to the source of lib.rs,
+// keeping both in year order
+pub fn register_year(lib: &str, year: u32) -> Result<String, String> {
+    let module = format!("year{}", year);
+    let declaration = format!("pub mod {};", module);
+    let entry = format!(
+        "    Year {{ number: {}, registry: {m}::registry, examples: {m}::examples::EXAMPLES, generators: {m}::generate::GENERATORS }},",
+        year,
+        m = module);
+
+    let mut lines: Vec<String> = lib.lines().map(|line| line.to_string()).collect();
+
+    if lines.iter().any(|line| line.trim() == declaration) {
+        return Err(format!("{} is already declared in lib.rs", module));
+    }
+
+    insert_in_order(&mut lines, &declaration, year, |line| {
+        line.strip_prefix("pub mod year")?.strip_suffix(';')?.parse().ok()
+    })?;
+
+    insert_in_order(&mut lines, &entry, year, |line| {
+        line.trim().strip_prefix("Year { number: ")?.split(|c: char| !c.is_ascii_digit()).next()?.parse().ok()
+    })?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Insert line before the first line for a later day, or after the last line for an
+// earlier one. day_of picks out the day a line is for, if it is one of the list.
+fn insert_in_order<F: Fn(&str) -> Option<u32>>(
+    lines: &mut Vec<String>,
+    line: &str,
+    day: u32,
+    day_of: F) -> Result<(), String> {
+
+    let days: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| day_of(l).map(|d| (i, d)))
+        .collect();
+
+    let position = match days.iter().find(|(_, d)| *d > day) {
+        Some((i, _)) => *i,
+        None => days.last().map(|(i, _)| i + 1).ok_or_else(|| format!("can't find where to add {:?}", line.trim()))?
+    };
+
+    lines.insert(position, line.to_string());
+    Ok(())
+}
+
+// Write the module for day into src/yearNNNN, register it in the year's mod.rs and
+// create an empty input. A year without a module gets one, registered in src/lib.rs.
+// Existing modules and inputs with anything in them are left alone.
+pub fn scaffold(root: &Path, year: u32, day: u32, title: &str) -> Result<Vec<String>, String> {
+    let year_dir = root.join(format!("src/year{}", year));
+    let module_path = year_dir.join(format!("day{:02}.rs", day));
+    let year_module_path = year_dir.join("mod.rs");
+    let lib_path = root.join("src/lib.rs");
+    let input_path = root.join(format!("input/{}/day{}.txt", year, day));
+
+    let read = |path: &Path| fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err));
+    let write = |path: &Path, text: &str| fs::write(path, text).map_err(|err| format!("cannot write {}: {}", path.display(), err));
+    let create_dir = |dir: &Path| fs::create_dir_all(dir).map_err(|err| format!("cannot create {}: {}", dir.display(), err));
+
+    if module_path.exists() && !read(&module_path)?.trim().is_empty() {
+        return Err(format!("{} already has code in it", module_path.display()));
+    }
+
+    let new_year = !year_module_path.exists();
+    let lib = if new_year { Some(register_year(&read(&lib_path)?, year)?) } else { None };
+    let year_module = if new_year { YEAR_TEMPLATE.replace("NNNN", &year.to_string()) } else { read(&year_module_path)? };
+    let year_module = register(&year_module, day, title)?;
+
+    let mut created = Vec::new();
+
+    if let Some(lib) = lib {
+        create_dir(&year_dir)?;
+        for (file, template) in [("examples.rs", EXAMPLES_TEMPLATE), ("generate.rs", GENERATORS_TEMPLATE)].iter() {
+            let path = year_dir.join(file);
+            write(&path, &template.replace("NNNN", &year.to_string()))?;
+            created.push(path.display().to_string());
+        }
+        write(&lib_path, &lib)?;
+        created.push(lib_path.display().to_string());
+    }
+
+    write(&module_path, &module_source(year, day))?;
+    created.push(module_path.display().to_string());
+
+    write(&year_module_path, &year_module)?;
+    created.push(year_module_path.display().to_string());
+
+    if !input_path.exists() {
+        if let Some(dir) = input_path.parent() {
+            create_dir(dir)?;
+        }
+        write(&input_path, "")?;
+        created.push(input_path.display().to_string());
+    }
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::tests::temp_dir;
+    use crate::scaffold::*;
+
+    const YEAR_MODULE: &str = "\
+pub mod examples;
+pub mod day01;
+pub mod day03;
+
+pub fn registry() -> Vec<Day> {
+    vec![
+        Day::new::<day01::ExpenseReport>(YEAR, 1, \"Report Repair\"),
+        Day::new::<day03::TobogganMap>(YEAR, 3, \"Toboggan Trajectory\"),
+    ]
+}
+";
+
+    #[test]
+    fn test_register_keeps_days_in_order() {
+        let year_module = register(YEAR_MODULE, 2, "Password Philosophy").unwrap();
+
+        assert_eq!("\
+pub mod examples;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub fn registry() -> Vec<Day> {
+    vec![
+        Day::new::<day01::ExpenseReport>(YEAR, 1, \"Report Repair\"),
+        Day::new::<day02::Puzzle>(YEAR, 2, \"Password Philosophy\"),
+        Day::new::<day03::TobogganMap>(YEAR, 3, \"Toboggan Trajectory\"),
+    ]
+}
+", year_module);
+    }
+
+    #[test]
+    fn test_register_appends_later_days() {
+        let year_module = register(YEAR_MODULE, 21, "Day 21").unwrap();
+
+        assert!(year_module.contains("pub mod day03;\npub mod day21;\n"));
+        assert!(year_module.contains("(YEAR, 3, \"Toboggan Trajectory\"),\n        Day::new::<day21::Puzzle>(YEAR, 21, \"Day 21\"),\n"));
+        assert!(register(&year_module, 21, "Day 21").is_err());
+    }
+
+    #[test]
+    fn test_register_first_day_of_a_year() {
+        let year_module = register(&YEAR_TEMPLATE.replace("NNNN", "2021"), 1, "Day 1").unwrap();
+
+        assert!(year_module.contains("pub mod generate;\n\npub mod day01;\n"));
+        assert!(year_module.contains("    vec![\n        Day::new::<day01::Puzzle>(YEAR, 1, \"Day 1\"),\n    ]"));
+        assert!(register(&year_module, 2, "Day 2").unwrap().contains("pub mod day01;\npub mod day02;\n"));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib = "\
+pub mod watch;
+
+pub mod year2020;
+
+pub const YEARS: &[Year] = &[
+    Year { number: 2020, registry: year2020::registry },
+];
+";
+
+        let lib = register_year(lib, 2021).unwrap();
+
+        assert!(lib.contains("pub mod year2020;\npub mod year2021;\n"));
+        assert!(lib.contains("year2020::registry },\n    Year { number: 2021, registry: year2021::registry, examples: year2021::examples::EXAMPLES, generators: year2021::generate::GENERATORS },\n];"));
+        assert!(register_year(&lib, 2021).is_err());
+    }
+
+    #[test]
+    fn test_module_source() {
+        let source = module_source(2021, 5);
+
+        assert!(source.contains("use crate::year2021::day05::*;"));
+        assert!(!source.contains("DAY"));
+        assert!(!source.contains("YEAR"));
+    }
+
+    #[test]
+    fn test_scaffold_starts_a_new_year() {
+        let root = temp_dir("scaffold");
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod year2020;\n\npub const YEARS: &[Year] = &[\n    Year { number: 2020 },\n];\n").unwrap();
+
+        let created = scaffold(&root, 2021, 1, "Day 1").unwrap();
+
+        assert_eq!(6, created.len());
+        assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().contains("pub mod year2021;"));
+        assert!(fs::read_to_string(root.join("src/year2021/mod.rs")).unwrap().contains("Day::new::<day01::Puzzle>(YEAR, 1, \"Day 1\")"));
+        assert!(root.join("input/2021/day1.txt").exists());
+
+        assert_eq!(3, scaffold(&root, 2021, 2, "Day 2").unwrap().len());
+        assert!(scaffold(&root, 2021, 2, "Day 2").is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use std::fmt;
+
+// The answer to one part of a puzzle. Every day reports its answers through this type
+// so the runner can print, compare and store them without knowing the day's own types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Unsolved
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "-")
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(n as i64)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+// A day's puzzle: parse the input once, then answer each part from the parsed state
+pub trait Solution {
+    fn parse(input: &str) -> Result<Self, AocError> where Self: Sized;
+    fn part1(&self) -> Result<Answer, AocError>;
+    fn part2(&self) -> Result<Answer, AocError>;
+
+    // What a part ends up with drawn as the puzzle description would, one character a
+    // cell, for the days where that is a picture (see crate::picture)
+    fn picture(&self, _part: u32) -> Result<Option<Grid<char>>, AocError> {
+        Ok(None)
+    }
+}
+
+// An entry in the registry of days the runner knows about
+pub struct Day {
+    pub year: u32,
+    pub number: u32,
+    pub title: &'static str,
+    parse: fn(&str) -> Result<Box<dyn Solution>, AocError>,
+}
+
+impl Day {
+    pub fn new<T: Solution + 'static>(year: u32, number: u32, title: &'static str) -> Day {
+
+        fn parse<T: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>, AocError> {
+            Ok(Box::new(T::parse(input)?))
+        }
+
+        Day {
+            year,
+            number,
+            title,
+            parse: parse::<T>
+        }
+    }
+
+    pub fn name(&self) -> String {
+        format!("day{}", self.number)
+    }
+
+    // The day's name qualified by its year, e.g. 2020.day7, which is also its section
+    // in the known answers file
+    pub fn id(&self) -> String {
+        format!("{}.day{}", self.year, self.number)
+    }
+
+    pub fn input_path(&self) -> String {
+        format!("input/{}/day{}.txt", self.year, self.number)
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Solution>, AocError> {
+        (self.parse)(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::*;
+
+    struct Sum {
+        numbers: Vec<u32>
+    }
+
+    impl Solution for Sum {
+        fn parse(input: &str) -> Result<Sum, AocError> {
+            Ok(Sum {
+                numbers: input.split(',').map(|n| n.parse().unwrap()).collect()
+            })
+        }
+
+        fn part1(&self) -> Result<Answer, AocError> {
+            Ok(self.numbers.iter().sum::<u32>().into())
+        }
+
+        fn part2(&self) -> Result<Answer, AocError> {
+            Ok(Answer::Unsolved)
+        }
+    }
+
+    #[test]
+    fn test_day_parses_into_solution() {
+        let day = Day::new::<Sum>(2020, 99, "Sum");
+        let solution = day.parse("1,2,3").unwrap();
+
+        assert_eq!("day99", day.name());
+        assert_eq!("2020.day99", day.id());
+        assert_eq!("input/2020/day99.txt", day.input_path());
+        assert_eq!(Ok(Answer::Number(6)), solution.part1());
+        assert_eq!("-", solution.part2().unwrap().to_string());
+        assert_eq!(Ok(None), solution.picture(1));
+    }
+}
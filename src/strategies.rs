@@ -0,0 +1,168 @@
+// Proptest strategies for generating puzzle inputs: valid ones from the days' input
+// generators, and near-valid ones made by damaging those and the worked examples,
+// which reach much deeper into a parser than random text does.
+use crate::generate::generate;
+use crate::YEARS;
+use proptest::prelude::*;
+
+// A small change of the kind a bad copy and paste or a typo makes
+#[derive(Debug, Clone)]
+pub enum Damage {
+    Delete(usize, usize),
+    Insert(usize, char),
+    Replace(usize, char),
+    DuplicateLine(usize),
+    Truncate(usize)
+}
+
+// Characters that mean something to at least one of the parsers
+const INTERESTING: &str = "0123456789 \n\r\t-+*#.:|\"()[]=,abcdefghijklmnopqrstuvwxyzLFRNESWX";
+
+fn interesting_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        4 => proptest::sample::select(INTERESTING.chars().collect::<Vec<_>>()),
+        1 => any::<char>()
+    ]
+}
+
+pub fn damage() -> impl Strategy<Value = Damage> {
+    let position = any::<usize>();
+    prop_oneof![
+        (position, 1..8usize).prop_map(|(at, len)| Damage::Delete(at, len)),
+        (position, interesting_char()).prop_map(|(at, c)| Damage::Insert(at, c)),
+        (position, interesting_char()).prop_map(|(at, c)| Damage::Replace(at, c)),
+        position.prop_map(Damage::DuplicateLine),
+        position.prop_map(Damage::Truncate)
+    ]
+}
+
+// Positions are taken modulo the length of the text so any usize will do
+pub fn apply(text: &str, damage: &Damage) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let at = |position: usize| if chars.is_empty() { 0 } else { position % chars.len() };
+    let join = |chars: &[char]| chars.iter().collect::<String>();
+
+    match *damage {
+        Damage::Delete(position, len) => {
+            let start = at(position);
+            let end = (start + len).min(chars.len());
+            join(&chars[..start]) + &join(&chars[end..])
+        },
+        Damage::Insert(position, c) => {
+            let mut chars = chars.clone();
+            chars.insert(at(position), c);
+            join(&chars)
+        },
+        Damage::Replace(position, c) => {
+            let mut chars = chars.clone();
+            if !chars.is_empty() {
+                chars[at(position)] = c;
+            }
+            join(&chars)
+        },
+        Damage::DuplicateLine(position) => {
+            let mut lines: Vec<&str> = text.lines().collect();
+            if !lines.is_empty() {
+                let i = position % lines.len();
+                lines.insert(i, lines[i]);
+            }
+            lines.join("\n")
+        },
+        Damage::Truncate(position) => join(&chars[..at(position)])
+    }
+}
+
+// A generated input for a year and day, no bigger than a real one and small enough to
+// solve quickly unoptimised. Sizes below what a day's generator needs are passed over.
+pub fn valid_input() -> impl Strategy<Value = (u32, u32, String)> {
+    let days: Vec<(u32, u32, usize)> = YEARS
+        .iter()
+        .flat_map(|year| year.generators.iter().map(move |(day, size, _)| (year.number, *day, *size)))
+        .collect();
+
+    (proptest::sample::select(days), 1..=40usize, any::<u64>()).prop_filter_map(
+        "too small for the day's generator",
+        |((year, day, real_size), size, seed)| {
+            generate(year, day, Some(size.min(real_size)), seed).ok().map(|input| (year, day, input))
+        }
+    )
+}
+
+// One of the worked examples or a generated input, for a year and day, with a few
+// things wrong with it
+pub fn near_valid_input() -> impl Strategy<Value = (u32, u32, String)> {
+    let examples: Vec<(u32, u32, String)> = YEARS
+        .iter()
+        .flat_map(|year| year.examples.iter().map(move |example| (year.number, example.day, example.input.to_string())))
+        .collect();
+
+    let input = prop_oneof![proptest::sample::select(examples), valid_input()];
+
+    (input, proptest::collection::vec(damage(), 1..4)).prop_map(|((year, day, input), damages)| {
+        let input = damages.iter().fold(input, |text, damage| apply(&text, damage));
+        (year, day, input)
+    })
+}
+
+// Text made mostly of characters the parsers care about, newlines included
+pub fn noisy_input() -> impl Strategy<Value = String> {
+    proptest::collection::vec(interesting_char(), 0..200).prop_map(|chars| chars.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{registry, YEARS};
+    use crate::strategies::*;
+
+    #[test]
+    fn test_apply_damage() {
+        assert_eq!("ad", apply("abcd", &Damage::Delete(1, 2)));
+        assert_eq!("abxc", apply("abc", &Damage::Insert(5, 'x')));
+        assert_eq!("a\nb\nb", apply("a\nb", &Damage::DuplicateLine(1)));
+        assert_eq!("", apply("", &Damage::Replace(3, 'x')));
+    }
+
+    // Parsing only: some solvers legitimately run for a long time on odd inputs
+    proptest! {
+        #[test]
+        fn prop_parse_never_panics_on_arbitrary_bytes(bytes in proptest::collection::vec(any::<u8>(), 0..300)) {
+            let input = String::from_utf8_lossy(&bytes);
+            for day in YEARS.iter().flat_map(|year| year.days()) {
+                let _ = day.parse(&input);
+            }
+        }
+
+        #[test]
+        fn prop_parse_never_panics_on_noisy_input(input in noisy_input()) {
+            for day in YEARS.iter().flat_map(|year| year.days()) {
+                let _ = day.parse(&input);
+            }
+        }
+
+        #[test]
+        fn prop_parse_never_panics_on_near_valid_input((year, number, input) in near_valid_input()) {
+            for day in registry(year).iter().filter(|day| day.number == number) {
+                let _ = day.parse(&input);
+            }
+        }
+
+        // Part 2 is left out for days 15 and 17 of 2020, which take a second or more
+        // unoptimised however small the input: 30 million turns, and six cycles of
+        // cubes spreading out in four dimensions. test_generated_inputs_solve has them.
+        #[test]
+        fn prop_valid_input_solves((year, number, input) in valid_input()) {
+            for day in registry(year).iter().filter(|day| day.number == number) {
+                let solution = day.parse(&input);
+                prop_assert!(solution.is_ok(), "{:?}", solution.err());
+                let solution = solution.unwrap();
+
+                let part1 = solution.part1();
+                prop_assert!(part1.is_ok(), "{:?}", part1);
+                if year != 2020 || ![15, 17].contains(&number) {
+                    let part2 = solution.part2();
+                    prop_assert!(part2.is_ok(), "{:?}", part2);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use crate::client::Client;
+use std::fmt;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_HISTORY_PATH: &str = "submissions.log";
+
+// What the website said about a submitted answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(String),
+    AlreadySolved,
+    Unrecognised
+}
+
+impl Outcome {
+    // The response is an HTML page; only the wording of its message matters
+    pub fn parse(response: &str) -> Outcome {
+        if response.contains("That's the right answer") {
+            Outcome::Correct
+        } else if response.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if response.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if response.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if response.contains("You gave an answer too recently") {
+            let wait = response
+                .split("You have ")
+                .nth(1)
+                .and_then(|s| s.split(" left to wait").next())
+                .unwrap_or("some time");
+            Outcome::RateLimited(wait.to_string())
+        } else if response.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unrecognised
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited(_) => "rate-limited",
+            Outcome::AlreadySolved => "already-solved",
+            Outcome::Unrecognised => "unrecognised"
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            "rate-limited" => Some(Outcome::RateLimited(String::new())),
+            "already-solved" => Some(Outcome::AlreadySolved),
+            "unrecognised" => Some(Outcome::Unrecognised),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(wait) => write!(f, "rate limited, wait {}", wait),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unrecognised => write!(f, "unrecognised response, check the website")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub time: u64,
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub outcome: Outcome,
+    pub answer: String
+}
+
+// Every answer submitted so far, one per line of the history file:
+//
+// 1607846400 2020 day7 part1 too-low 301
+//
+// The answer comes last so it may contain spaces.
+#[derive(Debug, Default)]
+pub struct History {
+    pub attempts: Vec<Attempt>
+}
+
+impl History {
+    // A missing file is an empty history
+    pub fn load(path: &Path) -> Result<History, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => History::parse(&text).map_err(|err| format!("{}:{}", path.display(), err)),
+            Err(_) if !path.exists() => Ok(History::default()),
+            Err(err) => Err(format!("cannot read {}: {}", path.display(), err))
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<History, String> {
+        let mut attempts = Vec::new();
+
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let invalid = || format!("{}: expected \"<time> <year> day<N> part<N> <outcome> <answer>\"", i + 1);
+
+            let (time, rest) = line.split_once(' ').ok_or_else(invalid)?;
+            let time = time.parse().map_err(|_| invalid())?;
+            let (year, rest) = rest.split_once(' ').ok_or_else(invalid)?;
+            let year = year.parse().map_err(|_| invalid())?;
+
+            let mut parts = rest.splitn(4, ' ');
+            let mut next = || parts.next().unwrap_or("");
+
+            let day = next().strip_prefix("day").and_then(|d| d.parse().ok()).ok_or_else(invalid)?;
+            let part = next().strip_prefix("part").and_then(|p| p.parse().ok()).ok_or_else(invalid)?;
+            let outcome = Outcome::from_name(next()).ok_or_else(invalid)?;
+            let answer = next().to_string();
+
+            attempts.push(Attempt { time, year, day, part, outcome, answer });
+        }
+
+        Ok(History { attempts })
+    }
+
+    pub fn append(path: &Path, attempt: &Attempt) -> Result<(), String> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(
+                file,
+                "{} {} day{} part{} {} {}",
+                attempt.time,
+                attempt.year,
+                attempt.day,
+                attempt.part,
+                attempt.outcome.name(),
+                attempt.answer))
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+
+    // Err explains why answer shouldn't be submitted: the part is already solved, the
+    // same answer was wrong before, or an earlier answer being too high or too low
+    // rules it out
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<(), String> {
+        let number: Option<i64> = answer.parse().ok();
+
+        for attempt in self.attempts.iter().filter(|a| a.year == year && a.day == day && a.part == part) {
+            let previous: Option<i64> = attempt.answer.parse().ok();
+
+            match (&attempt.outcome, number, previous) {
+                (Outcome::Correct, _, _) =>
+                    return Err(format!("already solved, the answer was {}", attempt.answer)),
+                (outcome, _, _) if outcome.is_wrong() && attempt.answer == answer =>
+                    return Err(format!("{} was already submitted and was {}", answer, outcome)),
+                (Outcome::TooHigh, Some(n), Some(p)) if n >= p =>
+                    return Err(format!("{} can't be right, {} was too high", answer, p)),
+                (Outcome::TooLow, Some(n), Some(p)) if n <= p =>
+                    return Err(format!("{} can't be right, {} was too low", answer, p)),
+                _ => ()
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Post answer for one part of a day of the client's year and record what the website
+// said about it
+pub fn submit(client: &mut Client, history_path: &Path, day: u32, part: u32, answer: &str) -> Result<Outcome, String> {
+    let history = History::load(history_path)?;
+    history.check(client.year, day, part, answer)?;
+
+    let response = client.post_form(
+        &format!("/{}/day/{}/answer", client.year, day),
+        &[("level", &part.to_string()), ("answer", answer)])?;
+
+    let outcome = Outcome::parse(&response);
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    History::append(history_path, &Attempt {
+        time,
+        year: client.year,
+        day,
+        part,
+        outcome: outcome.clone(),
+        answer: answer.to_string()
+    })?;
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::tests::{serve, temp_dir};
+    use crate::submit::*;
+    use std::time::Duration;
+
+    fn attempt(part: u32, outcome: Outcome, answer: &str) -> Attempt {
+        Attempt { time: 0, year: 2020, day: 7, part, outcome, answer: answer.to_string() }
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(Outcome::Correct, Outcome::parse("<p>That's the right answer!  You are one gold star closer"));
+        assert_eq!(Outcome::TooLow, Outcome::parse("That's not the right answer; your answer is too low."));
+        assert_eq!(Outcome::Wrong, Outcome::parse("That's not the right answer.  If you're stuck"));
+        assert_eq!(
+            Outcome::RateLimited("4m 20s".to_string()),
+            Outcome::parse("You gave an answer too recently. You have 4m 20s left to wait."));
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let history = History::parse("1607846400 2020 day7 part1 too-low 301\n\n1607846500 2019 day7 part2 correct a b\n").unwrap();
+
+        assert_eq!(vec![
+            Attempt { time: 1607846400, ..attempt(1, Outcome::TooLow, "301") },
+            Attempt { time: 1607846500, year: 2019, ..attempt(2, Outcome::Correct, "a b") },
+        ], history.attempts);
+        assert!(History::parse("day7 part1 correct 1").is_err());
+        assert!(History::parse("1607846400 twenty day7 part1 correct 1").is_err());
+    }
+
+    #[test]
+    fn test_history_needs_years() {
+        assert!(History::parse("1607846400 day7 part1 too-low 301 302\n").is_err());
+    }
+
+    #[test]
+    fn test_check_refuses_known_wrong_answers() {
+        let history = History {
+            attempts: vec![
+                attempt(1, Outcome::TooLow, "301"),
+                attempt(1, Outcome::TooHigh, "400"),
+                attempt(1, Outcome::Wrong, "372x"),
+                attempt(2, Outcome::Correct, "8015")
+            ]
+        };
+
+        assert_eq!(Ok(()), history.check(2020, 7, 1, "372"));
+        assert!(history.check(2020, 7, 1, "301").is_err());
+        assert!(history.check(2020, 7, 1, "250").is_err());
+        assert!(history.check(2020, 7, 1, "401").is_err());
+        assert!(history.check(2020, 7, 1, "372x").is_err());
+        assert!(history.check(2020, 7, 2, "1").is_err());
+        assert_eq!(Ok(()), history.check(2020, 8, 1, "301"));
+        assert_eq!(Ok(()), history.check(2019, 7, 2, "1"));
+    }
+
+    #[test]
+    fn test_submit_records_attempts() {
+        let (url, requests) = serve(vec![
+            (200, "<article><p>That's not the right answer; your answer is too high.</p></article>"),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let history_path = temp_dir("submit").join(DEFAULT_HISTORY_PATH);
+        let mut client = Client::new(&url, 2020, Some("abc123".to_string()));
+        client.min_interval = Duration::from_millis(0);
+
+        assert_eq!(Ok(Outcome::TooHigh), submit(&mut client, &history_path, 7, 1, "400"));
+        assert!(submit(&mut client, &history_path, 7, 1, "500").is_err());
+        assert_eq!(Ok(Outcome::Correct), submit(&mut client, &history_path, 7, 1, "372"));
+
+        let history = History::load(&history_path).unwrap();
+        assert_eq!(2, history.attempts.len());
+        assert_eq!(Outcome::Correct, history.attempts[1].outcome);
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("POST /2020/day/7/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=1&answer=400"));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::runner;
+use crate::runner::{format_duration, PartResult};
+use crate::solution::Day;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// The files a day is run against while watching: its input plus any examples saved
+// next to it as dayN_<name>.txt, e.g. input/2020/day20_test.txt
+pub fn watched_files(day: &Day, input: &Path) -> Vec<PathBuf> {
+    let prefix = format!("{}_", day.name());
+    let dir = input.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut examples: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .filter(|path| path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix)))
+            .collect())
+        .unwrap_or_default();
+
+    examples.sort();
+    examples.insert(0, input.to_path_buf());
+    examples
+}
+
+// When each watched file was last modified; None for a file that doesn't exist
+type Stamps = HashMap<PathBuf, Option<SystemTime>>;
+
+fn stamps(files: &[PathBuf]) -> Stamps {
+    files
+        .iter()
+        .map(|file| (file.clone(), fs::metadata(file).and_then(|m| m.modified()).ok()))
+        .collect()
+}
+
+// A part number, its outcome and how long it took
+pub type PartOutcome = (u32, String, Duration);
+
+// The outcome of one part on one file, as shown to the user and compared between runs
+fn outcome(part: &PartResult) -> String {
+    match &part.answer {
+        Ok(answer) => answer.to_string(),
+        Err(err) => format!("error: {}", err)
+    }
+}
+
+// Lines describing a run, each noting the previous outcome when it was different
+pub fn describe_run(
+    results: &[(PathBuf, Vec<PartOutcome>)],
+    previous: &HashMap<(PathBuf, u32), String>) -> Vec<String> {
+
+    let mut lines = Vec::new();
+
+    for (file, parts) in results {
+        for (part, outcome, elapsed) in parts {
+            let change = match previous.get(&(file.clone(), *part)) {
+                Some(before) if before != outcome => format!("  (was {})", before),
+                _ => String::new()
+            };
+            lines.push(format!(
+                "  {:<24} part{} {:>12}  {}{}",
+                file.display(),
+                part,
+                format_duration(*elapsed),
+                outcome,
+                change));
+        }
+    }
+
+    lines
+}
+
+// Run day against its input and examples every time one of them changes. Never returns.
+pub fn watch(day: &Day, input: &Path, timeout: Option<Duration>) -> ! {
+    let mut last_stamps = Stamps::new();
+    let mut previous: HashMap<(PathBuf, u32), String> = HashMap::new();
+    let mut run = 0;
+
+    loop {
+        let files = watched_files(day, input);
+        let current = stamps(&files);
+
+        if current != last_stamps {
+            let changed: Vec<String> = files
+                .iter()
+                .filter(|file| last_stamps.get(*file) != current.get(*file))
+                .map(|file| file.display().to_string())
+                .collect();
+
+            run += 1;
+            println!("run {}: {} changed", run, changed.join(", "));
+
+            let results: Vec<(PathBuf, Vec<PartOutcome>)> = files
+                .iter()
+                .map(|file| {
+                    let parts = match fs::read_to_string(file) {
+                        Ok(text) => runner::run_day_with_timeout(day, &text, timeout)
+                            .parts()
+                            .iter()
+                            .map(|(part, result)| (*part, outcome(result), result.elapsed))
+                            .collect(),
+                        Err(err) => (1..=2)
+                            .map(|part| (part, format!("error: {}", err), Duration::default()))
+                            .collect()
+                    };
+                    (file.clone(), parts)
+                })
+                .collect();
+
+            for line in describe_run(&results, &previous) {
+                println!("{}", line);
+            }
+            println!();
+
+            previous = results
+                .into_iter()
+                .flat_map(|(file, parts)| parts
+                    .into_iter()
+                    .map(move |(part, outcome, _)| ((file.clone(), part), outcome)))
+                .collect();
+            last_stamps = current;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::watch::*;
+    use crate::year2020::day20::Jigsaw;
+
+    #[test]
+    fn test_watched_files_include_examples() {
+        let day = Day::new::<Jigsaw>(2020, 20, "Jurassic Jigsaw");
+
+        let files = watched_files(&day, Path::new("input/2020/day20.txt"));
+
+        assert_eq!(Path::new("input/2020/day20.txt"), files[0]);
+        assert!(files.contains(&PathBuf::from("input/2020/day20_test.txt")));
+        assert!(files.iter().all(|file| !file.ends_with("day2.txt")));
+    }
+
+    #[test]
+    fn test_describe_run_shows_changes() {
+        let file = PathBuf::from("input/2020/day7.txt");
+        let results = vec![(file.clone(), vec![
+            (1, "372".to_string(), Duration::from_millis(2)),
+            (2, "8015".to_string(), Duration::from_millis(1)),
+        ])];
+        let previous: HashMap<_, _> = vec![
+            ((file.clone(), 1), "371".to_string()),
+            ((file, 2), "8015".to_string()),
+        ].into_iter().collect();
+
+        let lines = describe_run(&results, &previous);
+
+        assert!(lines[0].ends_with("(was 371)"));
+        assert!(!lines[1].contains("was"));
+    }
+}
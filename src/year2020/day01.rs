@@ -0,0 +1,47 @@
+use crate::error::{lines, AocError};
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+const TARGET: i64 = 2020;
+
+pub struct ExpenseReport {
+    entries: HashSet<i64>
+}
+
+impl Solution for ExpenseReport {
+    fn parse(input: &str) -> Result<ExpenseReport, AocError> {
+        Ok(ExpenseReport {
+            entries: lines(input)
+                .map(|line| line.parse(line.text, "a number"))
+                .collect::<Result<_, _>>()?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let pair = find_pair_that_sums_to_target(&self.entries, TARGET)
+            .ok_or_else(|| AocError::new(format!("no two entries sum to {}", TARGET)))?;
+        Ok((pair.0 * pair.1).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let triple = find_triple_that_sums_to_target(&self.entries, TARGET)
+            .ok_or_else(|| AocError::new(format!("no three entries sum to {}", TARGET)))?;
+        Ok((triple.0 * triple.1 * triple.2).into())
+    }
+}
+
+fn find_pair_that_sums_to_target(set: &HashSet<i64>, target: i64) -> Option<(i64, i64)> {
+    set
+        .iter()
+        .map(|x| (*x, target - *x))
+        .find(|p| set.contains(&p.1))
+}
+
+fn find_triple_that_sums_to_target(set: &HashSet<i64>, target: i64) -> Option<(i64, i64, i64)> {
+    set
+        .iter()
+        .map(|x| (*x, find_pair_that_sums_to_target(set, target - *x)))
+        .filter(|t| t.1.is_some())
+        .map(|t| (t.0, t.1.unwrap().0, t.1.unwrap().1))
+        .next()
+}
\ No newline at end of file
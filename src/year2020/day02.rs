@@ -0,0 +1,153 @@
+use crate::error::{lines, AocError, Line};
+use crate::solution::{Answer, Solution};
+use std::ops::Range;
+
+type Entry = (Box<dyn PasswordPolicy>, String);
+
+pub struct PasswordDatabase {
+    sled_entries: Vec<Entry>,
+    toboggan_entries: Vec<Entry>
+}
+
+impl Solution for PasswordDatabase {
+    fn parse(input: &str) -> Result<PasswordDatabase, AocError> {
+        let mut database = PasswordDatabase {
+            sled_entries: Vec::new(),
+            toboggan_entries: Vec::new()
+        };
+
+        for line in lines(input) {
+            let mut parts = line.text.split(':');
+            let policy = line.next(&mut parts, "a policy")?.trim();
+            let password = line.next(&mut parts, "':' followed by a password")?.trim();
+
+            database.sled_entries.push((
+                PassportPolicyType::Sled.build_policy(&line, policy)?,
+                password.to_string()));
+            database.toboggan_entries.push((
+                PassportPolicyType::Toboggan.build_policy(&line, policy)?,
+                password.to_string()));
+        }
+
+        Ok(database)
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(count_valid_passwords(&self.sled_entries).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(count_valid_passwords(&self.toboggan_entries).into())
+    }
+}
+
+fn count_valid_passwords(password_data: &[Entry]) -> usize {
+    password_data
+        .iter()
+        .filter(|(policy, password)| policy.validate(password))
+        .count()
+}
+
+trait PasswordPolicy {
+    fn new(line: &Line, policy: &str) -> Result<Self, AocError> where Self: Sized;
+    fn validate(&self, password: &str) -> bool;
+}
+
+enum PassportPolicyType {
+    Sled,
+    Toboggan
+}
+
+impl PassportPolicyType {
+    fn build_policy(&self, line: &Line, policy: &str) -> Result<Box<dyn PasswordPolicy>, AocError> {
+        Ok(match self {
+            PassportPolicyType::Sled => Box::new(SledPasswordPolicy::new(line, policy)?),
+            PassportPolicyType::Toboggan => Box::new(TobogganPasswordPolicy::new(line, policy)?)
+        })
+    }
+}
+
+struct SledPasswordPolicy {
+    range: Range<usize>,
+    letter: char
+}
+
+struct TobogganPasswordPolicy {
+    index1: usize,
+    index2: usize,
+    letter: char
+}
+
+// Both policies are written as "<number>-<number> <letter>"
+fn parse_policy(line: &Line, policy_text: &str) -> Result<(usize, usize, char), AocError> {
+    let mut parts = policy_text.split_whitespace();
+    let mut numbers = line.next(&mut parts, "<number>-<number>")?.split('-');
+
+    let first = line.parse(line.next(&mut numbers, "<number>-<number>")?, "a number")?;
+    let second = line.parse(line.next(&mut numbers, "-<number>")?, "a number")?;
+    let letter = line.next(&mut parts, "a letter")?;
+
+    if letter.chars().count() != 1 {
+        return Err(line.error(letter, "expected a single letter"));
+    }
+
+    Ok((first, second, letter.chars().next().unwrap()))
+}
+
+impl PasswordPolicy for SledPasswordPolicy {
+
+    fn new(line: &Line, policy_text: &str) -> Result<SledPasswordPolicy, AocError> {
+        let (min, max, letter) = parse_policy(line, policy_text)?;
+
+        Ok(SledPasswordPolicy {
+            range: min..(max + 1),
+            letter
+        })
+    }
+
+    fn validate(&self, password: &str) -> bool {
+        let char_count = password
+            .chars()
+            .filter(|c| *c == self.letter)
+            .count();
+
+        self.range.contains(&char_count)
+    }
+}
+
+impl PasswordPolicy for TobogganPasswordPolicy {
+
+    fn new(line: &Line, policy_text: &str) -> Result<TobogganPasswordPolicy, AocError> {
+        let (position1, position2, letter) = parse_policy(line, policy_text)?;
+
+        if position1 == 0 || position2 == 0 {
+            return Err(line.error(policy_text, "positions are counted from 1"));
+        }
+
+        Ok(TobogganPasswordPolicy {
+            index1: position1 - 1,
+            index2: position2 - 1,
+            letter
+        })
+    }   
+
+    fn validate(&self, password: &str) -> bool {
+        let first = password.chars().nth(self.index1);
+        let second = password.chars().nth(self.index2);
+        (first == Some(self.letter)) ^ (second == Some(self.letter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day02::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "1-3 a: abcde\n1-x b: cdefg";
+
+        let err = PasswordDatabase::parse(input).err().unwrap();
+
+        assert_eq!(r#"line 2, column 3: expected a number, found "x" in "1-x b: cdefg""#, err.to_string());
+    }
+}
\ No newline at end of file
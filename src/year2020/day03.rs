@@ -0,0 +1,71 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+
+// The map repeats to the right, so a square past the right edge wraps round
+pub struct TobogganMap {
+    trees: Grid<bool>
+}
+
+impl Solution for TobogganMap {
+    fn parse(input: &str) -> Result<TobogganMap, AocError> {
+        Ok(TobogganMap {
+            trees: parse_map(input)?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(calculate_collisions(&self.trees, 3, 1).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];    
+
+        Ok(slopes
+            .iter()
+            .map(|s| calculate_collisions(&self.trees, s.0, s.1))
+            .product::<u64>()
+            .into())
+    }
+}
+
+fn calculate_collisions(trees: &Grid<bool>, right: usize, down: usize) -> u64 {
+    (0..trees.height())
+        .step_by(down)
+        .filter(|y| is_tree_at_position(trees, (y / down) * right, *y))
+        .count() as u64
+}
+
+fn parse_map(input: &str) -> Result<Grid<bool>, AocError> {
+    Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    })
+}
+
+fn is_tree_at_position(trees: &Grid<bool>, x: usize, y: usize) -> bool {
+    trees[(x % trees.width(), y)]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day03::*;
+
+    #[test]
+    fn is_tree_at_position_expected() {
+        let trees = parse_map("..#..#......###.#...#......#..#").unwrap();
+
+        assert!(!is_tree_at_position(&trees, 0, 0));
+        assert!(is_tree_at_position(&trees, 2, 0));
+        assert!(is_tree_at_position(&trees, 33, 0));
+    }
+
+    #[test]
+    fn test_parse_map_rejects_unknown_squares() {
+        let err = parse_map("..#..#......###.#...#......#..#\n..#..#......#X#.#...#......#..#").unwrap_err();
+
+        let location = err.location.unwrap();
+        assert_eq!((2, 14), (location.line, location.column));
+    }
+}
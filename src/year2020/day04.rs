@@ -1,29 +1,51 @@
+use crate::error::{AocError, Line};
+use crate::input::{records, Record};
+use crate::solution::{Answer, Solution};
 use std::collections::HashMap;
-use std::fs;
 use regex::Regex;
 
-pub fn run() -> (usize, usize) {
-    
-    let input = fs::read_to_string("input/day4.txt").unwrap();
+type Passport = HashMap<String, String>;
 
-    (
-        input.split("\n\n").into_iter().filter(|line| validate(line)).count(), 
-        input.split("\n\n").into_iter().filter(|line| validate2(line)).count()
-    ) 
+pub struct PassportBatch {
+    passports: Vec<Passport>
 }
 
-fn validate(input: &str) -> bool {
-
-    let fields: HashMap<&str, &str> = input
-        .split_whitespace()
-        .map(|word| {
-            let mut field = word.split(":");
-            (
-                field.next().unwrap().trim(), 
-                field.next().unwrap().trim(),
-            )
+impl Solution for PassportBatch {
+    fn parse(input: &str) -> Result<PassportBatch, AocError> {
+        Ok(PassportBatch {
+            passports: records(input)
+                .iter()
+                .map(parse_passport)
+                .collect::<Result<_, _>>()?
         })
-        .collect();
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.passports.iter().filter(|passport| validate(passport)).count().into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(self.passports.iter().filter(|passport| validate2(passport)).count().into())
+    }
+}
+
+fn parse_passport(passport: &Record) -> Result<Passport, AocError> {
+    fn parse_field(line: &Line, word: &str) -> Result<(String, String), AocError> {
+        let mut field = word.split(':');
+        match (field.next(), field.next()) {
+            (Some(name), Some(value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+            _ => Err(line.error(word, "expected <field>:<value>"))
+        }
+    }
+
+    passport
+        .lines
+        .iter()
+        .flat_map(|line| line.text.split_whitespace().map(move |word| parse_field(line, word)))
+        .collect()
+}
+
+fn validate(fields: &Passport) -> bool {
 
     let required_fields = [
         "byr",
@@ -36,7 +58,7 @@ fn validate(input: &str) -> bool {
     ];
 
     for name in &required_fields {
-        let value = fields.get(name);
+        let value = fields.get(*name);
         if value.is_none() {
             return false;
         }
@@ -45,20 +67,11 @@ fn validate(input: &str) -> bool {
     true
 }
 
-fn validate2(input: &str) -> bool {
+type Validator = Box<dyn Fn(&str) -> bool>;
 
-    let fields: HashMap<&str, &str> = input
-        .split_whitespace()
-        .map(|word| {
-            let mut field = word.split(":");
-            (
-                field.next().unwrap().trim(), 
-                field.next().unwrap().trim(),
-            )
-        })
-        .collect();
+fn validate2(fields: &Passport) -> bool {
 
-    let required_fields: [(&str, Box<dyn Fn(&str)-> bool>); 7] = [
+    let required_fields: [(&str, Validator); 7] = [
         ("byr", Box::new(|value: &str| is_u32_in_range(value, 1920, 2002))),
         ("iyr", Box::new(|value: &str| is_u32_in_range(value, 2010, 2020))),
         ("eyr", Box::new(|value: &str| is_u32_in_range(value, 2020, 2030))),
@@ -69,8 +82,8 @@ fn validate2(input: &str) -> bool {
     ];
 
     for (name, validator) in &required_fields {
-        let value = fields.get(name);
-        if value.is_none() || !validator(&value.unwrap()) {
+        let value = fields.get(*name);
+        if value.is_none() || !validator(value.unwrap()) {
             return false;
         }
     }
@@ -86,7 +99,7 @@ fn is_u32_in_range(value: &str, start: u32, end: u32) -> bool {
         Err(_) => return false
     };
 
-    start <= result && result <= end 
+    (start..=end).contains(&result)
 }
 
 fn is_height(value: &str) -> bool {
@@ -98,12 +111,12 @@ fn is_height(value: &str) -> bool {
 
     if let Some(caps1) = RE1.captures(value) {
         let a = caps1.get(1).unwrap().as_str().parse::<u32>().unwrap();
-        return 150 <= a && a <= 193;
+        return (150..=193).contains(&a);
     }
 
     if let Some(caps2) = RE2.captures(value) {
         let a = caps2.get(1).unwrap().as_str().parse::<u32>().unwrap();
-        return 59 <= a && a <= 76;
+        return (59..=76).contains(&a);
 
     } 
 
@@ -0,0 +1,117 @@
+use crate::error::{lines, AocError, Line};
+use crate::solution::{Answer, Solution};
+use itertools::sorted;
+
+pub struct BoardingPasses {
+    seat_ids: Vec<u32>
+}
+
+impl Solution for BoardingPasses {
+    fn parse(input: &str) -> Result<BoardingPasses, AocError> {
+        Ok(BoardingPasses {
+            seat_ids: lines(input)
+                .map(|line| parse_boarding_pass(&line))
+                .collect::<Result<_, _>>()?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        self.seat_ids
+            .iter()
+            .max()
+            .map(|id| (*id).into())
+            .ok_or_else(|| AocError::new("no boarding passes"))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        if self.seat_ids.is_empty() {
+            return Err(AocError::new("no boarding passes"));
+        }
+
+        let ordered_seat_ids = sorted(self.seat_ids.iter().cloned());
+
+        Ok(find_my_site_id(ordered_seat_ids).into())
+    }
+}
+
+fn parse_boarding_pass(line: &Line) -> Result<u32, AocError> {
+    let pass = line.text.trim();
+
+    if let Some((i, c)) = pass.char_indices().find(|(_, c)| !"FBLR".contains(*c)) {
+        return Err(line.error(&pass[i..], format!("expected F, B, L or R, found {:?}", c)));
+    }
+
+    if pass.len() != 10 {
+        return Err(line.error(pass, format!("expected 10 letters, found {}", pass.len())));
+    }
+
+    Ok(calculate_seat_id(pass))
+}
+
+fn find_my_site_id(seats: Vec<u32>) -> u32 {
+
+    let mut i = *seats.first().unwrap();
+
+    for seat in seats {
+
+        if seat == i {
+            i += 1;
+        }
+        else if (seat - i) == 1 {
+            return i;
+        }
+        else {
+            i = seat + 1;
+        }
+    }
+
+    0  
+}
+
+fn calculate_seat_id(pass: &str) -> u32 {
+
+    let bin_str = pass
+        .replace("B", "1")
+        .replace("R", "1")
+        .replace("F", "0")
+        .replace("L", "0");
+
+    u32::from_str_radix(&bin_str, 2).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day05::*;
+
+    #[test]
+    fn test1() {
+        let boarding_pass = "BFFFBBFRRR";
+        assert_eq!(567, calculate_seat_id(boarding_pass));
+    }
+
+    #[test]
+    fn test2() {
+        let boarding_pass = "FFFBBBFRRR";
+        assert_eq!(119, calculate_seat_id(boarding_pass));
+    }
+
+    #[test]
+    fn test3() {
+        let boarding_pass = "BBFFBBFRLL";
+        assert_eq!(820, calculate_seat_id(boarding_pass));
+    }
+
+    #[test]
+    fn test_find_my_seat_1() {
+        let seats = vec![10, 11, 12, 13, 15, 16, 17];
+
+        assert_eq!(14, find_my_site_id(seats));
+    }
+
+    #[test]
+    fn test_find_my_seat_2() {
+        let seats = vec![10, 11, 12, 13, 16, 17, 19];
+
+        assert_eq!(18, find_my_site_id(seats));
+    }
+}
\ No newline at end of file
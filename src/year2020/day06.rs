@@ -0,0 +1,60 @@
+use crate::error::AocError;
+use crate::input::records;
+use crate::solution::{Answer, Solution};
+use std::hash::Hash;
+use std::collections::HashSet;
+
+pub struct CustomsForms {
+    // The answers of each person in each group
+    groups: Vec<Vec<String>>
+}
+
+impl Solution for CustomsForms {
+    fn parse(input: &str) -> Result<CustomsForms, AocError> {
+        Ok(CustomsForms {
+            groups: records(input)
+                .iter()
+                .map(|group| group.lines.iter().map(|line| line.text.trim().to_string()).collect())
+                .collect()
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.groups
+            .iter()
+            .map(|group| count_questions_where_anyone_answered_yes(group))
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(self.groups
+            .iter()
+            .map(|group| count_questions_where_everyone_answered_yes(group))
+            .sum::<usize>()
+            .into())
+    }
+}
+
+fn count_questions_where_anyone_answered_yes(group: &[String]) -> usize {
+    get_questions(&group.concat()).len()
+}
+
+fn count_questions_where_everyone_answered_yes(group: &[String]) -> usize {
+    let qs = group
+        .iter()
+        .fold(get_questions(&group.concat()), |all, person| intersection(all, &get_questions(person)));
+
+    qs.len()
+}
+
+fn get_questions(line: &str) -> HashSet<char> {
+    line
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .collect()
+}
+
+fn intersection<T: Eq + Hash>(a: HashSet<T>, b: &HashSet<T>) -> HashSet<T> {
+    a.into_iter().filter(|e| b.contains(e)).collect()
+}
\ No newline at end of file
@@ -0,0 +1,184 @@
+use crate::cancel;
+use crate::error::{lines, AocError, Line};
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+const MY_BAG: &str = "shiny gold";
+
+impl Solution for BagGraph {
+    fn parse(input: &str) -> Result<BagGraph, AocError> {
+        build_graph(input)
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.count_bags_containing(MY_BAG)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(self.count_bags_contained_in(MY_BAG)?.into())
+    }
+}
+
+fn build_graph(input: &str) -> Result<BagGraph, AocError> {
+    let rules: Vec<_> = lines(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| BagRule::parse(&line))
+        .collect::<Result<_, _>>()?;
+
+    Ok(BagGraph::build(rules))
+}
+
+pub struct BagGraph {
+    map: HashMap<String, Vec<(String, u32)>>
+}
+
+impl BagGraph {
+    pub fn build(rules: Vec< BagRule>) -> BagGraph {
+        
+        let mut graph = HashMap::new();
+    
+        for rule in rules {
+            graph.insert(rule.name, rule.bags);
+        }
+    
+        BagGraph {
+            map: graph
+        }
+    }
+
+    pub fn count_bags_containing(&self, bag: &str) -> Result<usize, AocError> {
+
+        // visited stops the search going round a cycle of rules forever
+        fn is_bag_inside<'a>(
+            graph: &'a BagGraph,
+            outer: &'a str,
+            inner: &str,
+            visited: &mut HashSet<&'a str>) -> Result<bool, AocError> {
+
+            cancel::check()?;
+
+            if outer == inner {
+                return Ok(true);
+            }
+
+            if !visited.insert(outer) {
+                return Ok(false);
+            }
+
+            for child in graph.map.get(outer).into_iter().flatten() {
+                if is_bag_inside(graph, &child.0, inner, visited)? {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        }
+
+        let mut count = 0;
+
+        for outer in self.map.keys().filter(|outer| *outer != bag) {
+            if is_bag_inside(self, outer, bag, &mut HashSet::new())? {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }    
+
+    pub fn count_bags_contained_in(&self, bag: &str) -> Result<u64, AocError> {
+
+        // path holds the bags we are inside; meeting one of them again means the
+        // rules say a bag contains itself and the count is infinite
+        fn count<'a>(graph: &'a BagGraph, bag: &'a str, path: &mut Vec<&'a str>) -> Result<u64, AocError> {
+
+            cancel::check()?;
+
+            if path.contains(&bag) {
+                return Err(AocError::new(format!("{} bags contain themselves", bag)));
+            }
+
+            path.push(bag);
+
+            let mut total = 0;
+            for child in graph.map.get(bag).into_iter().flatten() {
+                total += (child.1 as u64) * (1 + count(graph, &child.0, path)?);
+            }
+
+            path.pop();
+
+            Ok(total)
+        }
+
+        count(self, bag, &mut Vec::new())
+    }    
+}
+
+pub struct BagRule {
+    name: String,
+    bags: Vec<(String, u32)>
+}
+
+impl BagRule {
+    pub fn parse(line: &Line) -> Result<BagRule, AocError> {
+
+        let mut parts = line.text.split("bags contain");
+
+        let mut rule = BagRule {
+            name: parts.next().unwrap().trim().to_string(),
+            bags: Vec::new()
+        };
+
+        let bags_text = line.next(&mut parts, "\"<colour> bags contain\"")?.trim();
+
+        if !bags_text.ends_with('.') {
+            return Err(line.missing("'.'"));
+        }
+
+        let bags_text = bags_text.trim_end_matches('.');
+
+        if bags_text == "no other bags" {
+            return Ok(rule);
+        }
+
+        for bag_text in bags_text.split(',') {
+            let mut words = bag_text.split_whitespace();
+            let count = line.parse(line.next(&mut words, "a number of bags")?, "a number of bags")?;
+            let name = format!(
+                "{} {}",
+                line.next(&mut words, "a colour")?,
+                line.next(&mut words, "a colour")?);
+            rule.bags.push((name, count));
+        }
+
+        Ok(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day07::*;
+    use crate::year2020::examples;
+
+    #[test]
+    fn test1() {
+        let input = examples::input(7, "deep");
+
+        let graph = build_graph(input).unwrap();
+
+        assert_eq!(Ok(126), graph.count_bags_contained_in("shiny gold"));
+    }
+
+    #[test]
+    fn test_cyclic_rules() {
+        let input = r"
+            shiny gold bags contain 1 dark red bag.
+            dark red bags contain 2 shiny gold bags.
+            light blue bags contain 1 dark red bag.
+            ";
+
+        let graph = build_graph(input).unwrap();
+
+        assert_eq!(Ok(2), graph.count_bags_containing("shiny gold"));
+        assert!(graph.count_bags_contained_in("shiny gold").is_err());
+    }
+}
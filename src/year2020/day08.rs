@@ -0,0 +1,259 @@
+use crate::error::{lines, AocError, Line};
+use crate::solution::{Answer, Solution};
+use crate::year2020::day08::Instruction::*;
+use std::fmt;
+
+pub struct BootCode {
+    instructions: Vec<Instruction>
+}
+
+impl Solution for BootCode {
+    fn parse(input: &str) -> Result<BootCode, AocError> {
+        Ok(BootCode {
+            instructions: Program::load_instructions_from_str(input)?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(run_original_program(&self.instructions).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut instructions = self.instructions.clone();
+        fix_program(&mut instructions)
+            .map(|value| value.into())
+            .ok_or_else(|| AocError::new("no single jmp -> nop change lets the program terminate"))
+    }
+}
+
+fn run_original_program(instructions: &[Instruction]) -> i32 {
+    
+    let mut program = Program::load(instructions);
+    let result = program.run();
+
+    match result {
+        Ok(value) => value,
+        Err(err) => {
+            log::debug!("{}", err.message);
+            log::debug!("program exited at instruction {}", err.counter);
+            err.value
+        }
+    }
+}
+
+pub fn fix_program(instructions: &mut [Instruction]) -> Option<i32> {
+    
+    for i in 0..instructions.len() {
+        let instruction = instructions[i];
+
+        if let Jmp(_) = instruction {
+            instructions[i] = Nop;
+            let mut program = Program::load(instructions);
+            let result = program.run();
+            instructions[i] = instruction;
+
+            match result {
+                Ok(value) => return Some(value),
+                Err(err) => {
+                    log::debug!("{}", err.message);
+                    log::debug!("program exited at instruction {}", err.counter);
+                    log::debug!("");
+                }
+            }
+        }
+    } 
+
+    None
+}
+
+#[derive(Debug)]
+pub struct Program {
+    instructions: Vec<(Instruction, bool)>,
+    counter: i32,
+    value: i32,
+}
+
+impl Program {
+
+    pub fn load_instructions_from_str(input: &str) -> Result<Vec<Instruction>, AocError> {
+
+        fn parse_instruction(line: &Line) -> Result<Instruction, AocError> {
+
+            let mut parts = line.text.split_whitespace();
+
+            let instruction = line.next(&mut parts, "an instruction")?;
+            let value_text = line.next(&mut parts, "a value")?;
+            let value = line.parse(value_text.trim_start_matches('+'), "a signed number")?;
+
+            match instruction {
+                "acc" => Ok(Acc(value)),
+                "jmp" => Ok(Jmp(value)),
+                "nop" => Ok(Nop),
+                _ => Err(line.error(instruction, "expected acc, jmp or nop"))
+            }
+        }
+
+        lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| parse_instruction(&line))
+            .collect()
+    }
+
+    pub fn load(instructions: &[Instruction]) -> Program {
+        Program {
+            counter: 0,
+            value: 0,
+            instructions: instructions
+                .iter()
+                .map(|i| (*i, false))
+                .collect()
+        }
+    }
+
+    pub fn run(&mut self) -> Result<i32, ProgramErr> {
+
+        let mut i = 0;
+
+        loop {
+            log::debug!(
+                "Counter {}, Value {}, Iteration {}",
+                self.counter,
+                self.value,
+                i);
+
+            if self.counter >= (self.instructions.len() as i32) {
+                return Ok(self.value);
+            }
+
+            if self.counter < 0 {
+                return Err(ProgramErr { 
+                    message: String::from("counter < 0"),
+                    value: self.value, 
+                    counter: self.counter
+                });
+            }
+
+            let instruction = &mut self.instructions[self.counter as usize];
+            
+            if instruction.1 {
+                return Err(ProgramErr { 
+                    message: String::from("loop detected"),
+                    value: self.value, 
+                    counter: self.counter
+                });                
+            }
+
+            let (value, counter) = match instruction.0 {
+                Acc(value) => (self.value.checked_add(value), self.counter.checked_add(1)),
+                Jmp(value) => (Some(self.value), self.counter.checked_add(value)),
+                Nop => (Some(self.value), self.counter.checked_add(1))
+            };
+
+            match (value, counter) {
+                (Some(value), Some(counter)) => {
+                    self.value = value;
+                    self.counter = counter;
+                },
+                _ => return Err(ProgramErr {
+                    message: String::from("overflow"),
+                    value: self.value,
+                    counter: self.counter
+                })
+            }
+
+            instruction.1 = true;
+
+            i += 1;
+        }
+    }   
+}
+
+#[derive(Debug)]
+pub struct ProgramErr {
+    pub message: String,
+    pub value: i32,
+    pub counter: i32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Instruction {
+    Nop,
+    Acc(i32),
+    Jmp(i32)
+}
+
+// In the puzzle's own format, so a printed program loads back the same. A nop's
+// argument is never used, so it isn't kept and prints as +0.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Nop => write!(f, "nop +0"),
+            Acc(value) => write!(f, "acc {:+}", value),
+            Jmp(offset) => write!(f, "jmp {:+}", offset)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day08::*;
+    use crate::year2020::examples;
+    use proptest::prelude::*;
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            Just(Nop),
+            any::<i32>().prop_map(Acc),
+            any::<i32>().prop_map(Jmp)
+        ]
+    }
+
+    #[test]
+    fn test_program() {
+        let input = examples::input(8, "example");
+
+        let mut program = Program::load(&Program::load_instructions_from_str(input).unwrap());
+        let result = program.run();
+
+        assert!(result.is_err());
+        assert_eq!(5, result.unwrap_err().value);
+    }
+
+    #[test]
+    fn test_fix_program() {
+        let input = examples::input(8, "example");
+
+        let mut instructions = Program::load_instructions_from_str(input).unwrap();
+
+        let result = fix_program(&mut instructions);
+
+        assert!(result.is_some());
+        assert_eq!(8, result.unwrap());
+    }
+
+    #[test]
+    fn test_load_instructions_reports_location() {
+        let input = "nop +0\nacc +1\njump +4";
+
+        let err = Program::load_instructions_from_str(input).unwrap_err();
+
+        assert_eq!(r#"line 3, column 1: expected acc, jmp or nop in "jump +4""#, err.to_string());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_printed_programs_load_back(program in proptest::collection::vec(instruction(), 0..50)) {
+            let text: Vec<String> = program.iter().map(|instruction| instruction.to_string()).collect();
+
+            let loaded = Program::load_instructions_from_str(&text.join("\n")).unwrap();
+
+            prop_assert_eq!(program, loaded);
+        }
+
+        #[test]
+        fn prop_running_any_program_terminates(program in proptest::collection::vec(instruction(), 0..50)) {
+            // Either it runs off the end or it is stopped on repeating an instruction
+            let _ = Program::load(&program).run();
+        }
+    }
+}
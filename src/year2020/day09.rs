@@ -0,0 +1,137 @@
+use crate::cancel;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+const WINDOW_SIZE: usize = 25;
+
+pub struct XmasData {
+    numbers: Vec<i64>
+}
+
+impl Solution for XmasData {
+    fn parse(input: &str) -> Result<XmasData, AocError> {
+        let numbers: Vec<i64> = input
+            .split_whitespace()
+            .map(|word| word
+                .parse()
+                .map_err(|_| AocError::within(input, word, "expected a number")))
+            .collect::<Result<_, _>>()?;
+
+        if numbers.len() <= WINDOW_SIZE {
+            return Err(AocError::new(format!(
+                "expected more than {} numbers, found {}", WINDOW_SIZE, numbers.len())));
+        }
+
+        Ok(XmasData {
+            numbers
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.find_anomaly()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let anomaly = self.find_anomaly()?;
+        find_xmas_encryption_weakness(&self.numbers, anomaly)?
+            .map(|weakness| weakness.into())
+            .ok_or_else(|| AocError::new(format!("no contiguous range sums to {}", anomaly)))
+    }
+}
+
+impl XmasData {
+    fn find_anomaly(&self) -> Result<i64, AocError> {
+        find_xmas_anomaly(&self.numbers, WINDOW_SIZE)?
+            .ok_or_else(|| AocError::new("every number is the sum of two of the previous ones"))
+    }
+}
+
+fn find_xmas_anomaly(numbers: &[i64], window_size: usize) -> Result<Option<i64>, AocError> {
+
+    fn is_nth_valid(n: i64, prev_x: &HashSet<i64>) -> bool {
+        prev_x
+            .iter()
+            .any(|x| prev_x.contains(&(n - *x)))
+    }
+
+    
+    let mut prev_x: HashSet<i64> = numbers[0..window_size]
+        .iter()
+        .copied()
+        .collect();
+
+    for nth in window_size..numbers.len() {
+        cancel::check_every(nth)?;
+
+        let n = numbers[nth];
+
+        if !is_nth_valid(n, &prev_x) {
+            return Ok(Some(n));
+        }
+
+        prev_x.remove(&numbers[nth - window_size]);
+        prev_x.insert(n);
+    }
+
+    Ok(None)
+}
+
+fn find_xmas_encryption_weakness(numbers: &[i64], anomaly: i64) -> Result<Option<i64>, AocError> {
+
+    for i in 0..numbers.len() {
+        cancel::check()?;
+
+        let mut sum = 0_i64;
+
+        for (j, number) in numbers.iter().enumerate().skip(i) {
+            sum += number;
+
+            // The range needs at least two numbers, which rules out the anomaly itself
+            if sum == anomaly && j > i {
+                let range = &numbers[i..=j];
+                let min = range.iter().min().unwrap();
+                let max = range.iter().max().unwrap();
+                return Ok(Some(min + max));
+            }
+        }
+    }
+
+    Ok(None)
+} 
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day09::*;
+
+    #[test]
+    fn test_find_xmas_anomaly() {
+        let input = vec![35,20,15,25,47,40,62,55,65,95,102,117,150,182,127,219,299,277,309,576];
+
+        let result = find_xmas_anomaly(&input, 5);
+
+        assert_eq!(Ok(Some(127)), result);
+    }
+
+    #[test]
+    fn test_find_encryption_weakness() {
+        let input = vec![35,20,15,25,47,40,62,55,65,95,102,117,150,182,127,219,299,277,309,576];
+
+        let result = find_xmas_encryption_weakness(&input, 127);
+
+        assert_eq!(Ok(Some(62)), result);
+    }    
+
+    #[test]
+    fn test_encryption_weakness_range() {
+        // The last number of the range counts towards both the sum and the weakness
+        assert_eq!(Ok(Some(5)), find_xmas_encryption_weakness(&[1, 2, 3], 5));
+
+        // Nothing but the anomaly on its own adds up to it
+        let mut input: Vec<i64> = (0..25).map(|i| 1 << i).collect();
+        input.push(3 << 25);
+
+        assert_eq!(Ok(Some(3 << 25)), find_xmas_anomaly(&input, 25));
+        assert_eq!(Ok(None), find_xmas_encryption_weakness(&input, 3 << 25));
+    }
+}
\ No newline at end of file
@@ -1,26 +1,43 @@
-use std::fs;
+use crate::cancel;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 
-pub fn run() -> (usize, usize) {
+pub struct Adaptors {
+    joltages: Vec<usize>
+}
 
-    let input = fs::read_to_string("input/day10.txt").unwrap();
+impl Solution for Adaptors {
+    fn parse(input: &str) -> Result<Adaptors, AocError> {
+        let joltages = get_sorted_adaptors(input)?;
 
-    let adaptors = get_sorted_adaptors(&input);
+        if let Some(gap) = joltages.windows(2).find(|w| w[1] - w[0] > 3) {
+            return Err(AocError::new(format!(
+                "no adaptor bridges the gap from {} to {} jolts", gap[0], gap[1])));
+        }
 
-    let diffs = count_differences(&adaptors, 3);
-    let combinations = count_valid_combinations(&adaptors);
+        Ok(Adaptors {
+            joltages
+        })
+    }
 
-    (
-        diffs[1] * diffs[3], 
-        combinations
-    )
+    fn part1(&self) -> Result<Answer, AocError> {
+        let diffs = count_differences(&self.joltages, 3);
+        Ok((diffs[1] * diffs[3]).into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(count_valid_combinations(&self.joltages)?.into())
+    }
 }
 
-fn get_sorted_adaptors(input: &str) -> Vec<usize> {
+fn get_sorted_adaptors(input: &str) -> Result<Vec<usize>, AocError> {
 
     let mut adaptors: Vec<usize> = input
         .split_whitespace()
-        .map(|word| word.trim().parse().unwrap())
-        .collect();    
+        .map(|word| word
+            .parse()
+            .map_err(|_| AocError::within(input, word, "expected a joltage")))
+        .collect::<Result<_, _>>()?;    
 
     // Add the "charging outlet" to the end of the list of adaptors
     // It will be sorted to the front in the following step
@@ -32,13 +49,13 @@ fn get_sorted_adaptors(input: &str) -> Vec<usize> {
     // Add the "built-in adaptor" to the end of the list
     adaptors.push(*adaptors.last().unwrap() + 3_usize);
 
-    adaptors
+    Ok(adaptors)
 }
 
 // This function allows for adaptors to work with a given max difference in joltage
 // rather than being hard-coded to 3. It also counts all dfferences rather than assume 
 // they will always be 1s or 3s.
-fn count_differences(adaptors: &Vec<usize>, max_diff: usize) -> Vec<usize> {
+fn count_differences(adaptors: &[usize], max_diff: usize) -> Vec<usize> {
     let mut results = vec![0; max_diff + 1];
     for window in adaptors.windows(2) {
         let diff = window[1] - window[0];
@@ -59,14 +76,17 @@ fn count_differences(adaptors: &Vec<usize>, max_diff: usize) -> Vec<usize> {
 // 1,1,1... -> 1 + (((n - 1) * n) / 2)
 // We thus multiply together the combinations due to each sequence of 1s to find the total
 // number of combinations of adaptors
-fn count_valid_combinations(nums: &[usize]) -> usize {
-
-    fn combinations(n: usize) -> usize {
-        match n {
-            0 | 1 => 1,
-            2 => 2,
-            i => combinations(i-1) + combinations(i-2) + combinations(i-3)
+fn count_valid_combinations(nums: &[usize]) -> Result<usize, AocError> {
+
+    // Each count is the sum of the three before it, worked out from the bottom up so
+    // that a long sequence of 1s takes as many steps as it has 1s
+    fn combinations(n: usize) -> Result<usize, AocError> {
+        let (mut a, mut b, mut c) = (1, 1, 2);
+        for i in 0..n {
+            cancel::check_every(i)?;
+            (a, b, c) = (b, c, a + b + c);
         }
+        Ok(a)
     }
 
     nums
@@ -75,46 +95,47 @@ fn count_valid_combinations(nums: &[usize]) -> usize {
         .split(|n| n[1] - n[0] == 3)
         .filter(|n| n.len() > 1)
         .map(|n| combinations(n.len()))
-        .product::<usize>()    
+        .product::<Result<usize, AocError>>()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day10::*;
+    use crate::year2020::day10::*;
+    use crate::year2020::examples;
 
     #[test]
     fn test_count_differences() {
-        let input = "16 10 15 5 1 11 7 19 6 12 4";
+        let input = examples::input(10, "small");
         assert_eq!(35, test_part1(input));
     }
 
     #[test]
     fn test_count_differences_long() {
-        let input = "28 33 18 42 31 14 46 20 48 47 24 23 49 45 19 38 39 11 1 32 25 35 8 17 7 9 4 2 34 10 3";
+        let input = examples::input(10, "large");
         assert_eq!(220, test_part1(input));
 
     }
 
     fn test_part1(input: &str) -> usize {
-        let adaptors = get_sorted_adaptors(input);
+        let adaptors = get_sorted_adaptors(input).unwrap();
         let diffs = count_differences(&adaptors, 3);
-        return diffs[1] * diffs[3];
+        diffs[1] * diffs[3]
     }
 
     #[test]
     fn test_count_valid_combinations() {
-        let input = "16 10 15 5 1 11 7 19 6 12 4";
+        let input = examples::input(10, "small");
         assert_eq!(8, test_part2(input));        
     }
 
     #[test]
     fn test_count_valid_combinations_long() {
-        let input = "28 33 18 42 31 14 46 20 48 47 24 23 49 45 19 38 39 11 1 32 25 35 8 17 7 9 4 2 34 10 3";
+        let input = examples::input(10, "large");
         assert_eq!(19208, test_part2(input));        
     }
 
     fn test_part2(input: &str) -> usize {
-        let adaptors = get_sorted_adaptors(input);
-        count_valid_combinations(&adaptors)
+        let adaptors = get_sorted_adaptors(input).unwrap();
+        count_valid_combinations(&adaptors).unwrap()
     }
 }
\ No newline at end of file
@@ -0,0 +1,286 @@
+use crate::cancel;
+use crate::error::AocError;
+use crate::grid::{Grid, ALL_DIRECTIONS};
+use crate::solution::{Answer, Solution};
+use std::fmt;
+
+pub struct SeatLayout {
+    floor_plan: Grid<Space>
+}
+
+impl Solution for SeatLayout {
+    fn parse(input: &str) -> Result<SeatLayout, AocError> {
+        Ok(SeatLayout {
+            floor_plan: load_floor_plan(input)?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(count_occupied_in_steady_state(&mut self.floor_plan.clone(), &AdjacentPolicy{})?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(count_occupied_in_steady_state(&mut self.floor_plan.clone(), &VisiblePolicy{})?.into())
+    }
+
+    // The seats once they have settled
+    fn picture(&self, part: u32) -> Result<Option<Grid<char>>, AocError> {
+        let policy: &dyn SeatingPolicy = if part == 1 { &AdjacentPolicy{} } else { &VisiblePolicy{} };
+        let mut floor_plan = self.floor_plan.clone();
+        count_occupied_in_steady_state(&mut floor_plan, policy)?;
+        Ok(Some(floor_plan.map(Space::symbol)))
+    }
+}
+
+fn count_occupied_in_steady_state(
+    floor_plan: &mut Grid<Space>, 
+    seating_policy: &dyn SeatingPolicy) -> Result<usize, AocError> {
+
+    for i in 0..99999 {
+        cancel::check()?;
+
+        let mut new_floor_plan = floor_plan.clone();
+
+        if !play_round(floor_plan, &mut new_floor_plan, seating_policy) {
+            return Ok(count_occupied(&new_floor_plan));
+        }
+
+        *floor_plan = new_floor_plan;
+
+        if i % 1000 == 0 {
+            log::debug!("{} iterations", i);
+        }
+    }
+
+    Err(AocError::new("Unstable floor plan!"))
+}
+
+fn play_round(
+    floor_plan: &Grid<Space>, 
+    new_floor_plan: &mut Grid<Space>, 
+    seating_policy: &dyn SeatingPolicy) -> bool {
+
+    let mut has_changed = false;
+
+    for (x, y) in floor_plan.positions() {
+
+        let space = floor_plan[(x, y)];
+
+        let space = match space {
+            Space::Floor => Space::Floor,
+            Space::Empty => 
+                if seating_policy.should_become_occupied(floor_plan, x, y) { 
+                    has_changed = true; 
+                    Space::Occupied 
+                } else { 
+                    Space::Empty 
+                },
+            Space::Occupied => 
+                if seating_policy.should_become_empty(floor_plan, x, y) { 
+                    has_changed = true; 
+                    Space::Empty 
+                } else { 
+                    Space::Occupied 
+                }
+        };
+
+        new_floor_plan[(x, y)] = space;
+    }
+
+    has_changed
+}
+
+fn count_occupied(floor_plan: &Grid<Space>) -> usize {
+    floor_plan
+        .iter()
+        .filter(|s| **s == Space::Occupied)
+        .count()
+}
+
+trait SeatingPolicy {
+    fn should_become_empty(&self, floor_plan: &Grid<Space>, x: usize, y: usize) -> bool;
+    fn should_become_occupied(&self, floor_plan: &Grid<Space>, x: usize, y: usize) -> bool;
+}
+
+struct AdjacentPolicy {}
+
+impl AdjacentPolicy {
+    fn adjacent_occupied_seats(floor_plan: &Grid<Space>, x: usize, y:usize) -> usize {
+        floor_plan
+            .neighbours8(x, y)
+            .filter(|p| floor_plan[*p] == Space::Occupied)
+            .count()
+    }    
+}
+
+impl SeatingPolicy for AdjacentPolicy {
+    fn should_become_empty(&self, floor_plan: &Grid<Space>, x: usize, y: usize) -> bool {
+        AdjacentPolicy::adjacent_occupied_seats(floor_plan, x, y) >= 4
+    }
+    fn should_become_occupied(&self, floor_plan: &Grid<Space>, x: usize, y: usize) -> bool {
+        AdjacentPolicy::adjacent_occupied_seats(floor_plan, x, y) < 1
+    }
+}
+
+struct VisiblePolicy {}
+
+impl VisiblePolicy {
+    fn first_seat_in_direction(floor_plan: &Grid<Space>, x: usize, y:usize, direction: (isize, isize)) -> Space {
+        floor_plan
+            .ray(x, y, direction)
+            .map(|p| floor_plan[p])
+            .find(|space| *space != Space::Floor)
+            .unwrap_or(Space::Floor)
+    }
+
+    fn visible_occupied_seats(floor_plan: &Grid<Space>, x: usize, y:usize) -> usize {
+        ALL_DIRECTIONS
+            .iter()
+            .map(|d| VisiblePolicy::first_seat_in_direction(floor_plan, x, y, *d))
+            .filter(|s| *s == Space::Occupied)
+            .count()
+    }    
+}
+
+impl SeatingPolicy for VisiblePolicy {
+
+    fn should_become_empty(&self, floor_plan: &Grid<Space>, x: usize, y: usize) -> bool {
+        VisiblePolicy::visible_occupied_seats(floor_plan, x, y) >= 5
+    }
+
+    fn should_become_occupied(&self, floor_plan: &Grid<Space>, x: usize, y: usize) -> bool {
+        VisiblePolicy::visible_occupied_seats(floor_plan, x, y) < 1
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Space {
+    Floor,
+    Empty,
+    Occupied
+}
+
+impl Space {
+    fn symbol(&self) -> char {
+        match self {
+            Space::Floor => '.',
+            Space::Empty => 'L',
+            Space::Occupied => '#'
+        }
+    }
+}
+
+impl fmt::Display for Space {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+fn load_floor_plan(input: &str) -> Result<Grid<Space>, AocError> {
+    Grid::parse(input, |c| match c {
+        'L' => Some(Space::Empty),
+        '#' => Some(Space::Occupied),
+        '.' => Some(Space::Floor),
+        _ => None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day11::*;
+    use crate::year2020::examples;
+
+    #[test]
+    fn test_floor_plan() {
+        let input = examples::input(11, "seats");
+
+        let floor_plan = load_floor_plan(input).unwrap();
+
+        assert_eq!(Space::Floor, floor_plan[(1, 0)]);
+        assert_eq!(Space::Empty, floor_plan[(0, 1)]);
+        assert_eq!(input, floor_plan.to_string());
+    }
+
+    #[test]
+    fn test_count_occupied_in_steady_state() {
+        let input = examples::input(11, "seats");
+
+        let mut floor_plan = load_floor_plan(input).unwrap();
+        
+        let result = count_occupied_in_steady_state(&mut floor_plan, &AdjacentPolicy{}).unwrap();
+
+        assert_eq!(37, result);
+    }
+
+    #[test]
+    fn test_picture_of_settled_seats() {
+        let layout = SeatLayout::parse(examples::input(11, "seats")).unwrap();
+
+        let picture = layout.picture(1).unwrap().unwrap();
+
+        assert_eq!(37, picture.iter().filter(|c| **c == '#').count());
+        assert_eq!("#.#L.L#.##\n", &picture.to_string()[..11]);
+    }
+
+    #[test]
+    fn test_count_occupied_in_steady_state_with_visibilty_policy() {
+        let input = examples::input(11, "seats");
+
+        let mut floor_plan = load_floor_plan(input).unwrap();
+        
+        let result = count_occupied_in_steady_state(&mut floor_plan, &VisiblePolicy{}).unwrap();
+
+        assert_eq!(26, result);
+    }
+
+    // Part 2 used to carry on from where part 1 left the seats
+    #[test]
+    fn test_parts_start_from_the_same_layout() {
+        let layout = SeatLayout::parse(examples::input(11, "seats")).unwrap();
+
+        assert_eq!(Ok(Answer::Number(37)), layout.part1());
+        assert_eq!(Ok(Answer::Number(26)), layout.part2());
+        assert_eq!(Ok(Answer::Number(37)), layout.part1());
+    }
+
+    #[test]
+    fn test_number_occupied_seen() {
+        let input = r"
+.......#.
+...#.....
+.#.......
+.........
+..#L....#
+....#....
+.........
+#........
+...#.....";
+
+        let floor_plan = load_floor_plan(input).unwrap();
+
+        let num_visible = VisiblePolicy::visible_occupied_seats(&floor_plan, 3, 4);
+
+        assert_eq!(8, num_visible);
+    }
+
+    #[test]
+    fn test_number_occupied_seen_2() {
+        let input = r"
+.##.##.
+#.#.#.#
+##...##
+...L...
+##...##
+#.#.#.#
+.##.##.";
+
+        let floor_plan = load_floor_plan(input).unwrap();
+
+        assert_eq!(7, floor_plan.height());
+        assert_eq!(7, floor_plan.width());
+
+        let num_visible = VisiblePolicy::visible_occupied_seats(&floor_plan, 3, 3);
+
+        assert_eq!(0, num_visible);
+    }
+}
\ No newline at end of file
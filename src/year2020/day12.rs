@@ -1,22 +1,36 @@
-use crate::day12::part1::Pose;
-use std::fs;
-
-pub fn run() -> (usize, usize) {
-    let input = fs::read_to_string("input/day12.txt").unwrap();
-    let instructions = load_instructions(&input);
-    let final_position_1 = part1::navigate(Pose::new(), &instructions);
-    let final_position_2 = part2::Ship::new(part2::Waypoint::new(10, 1)).navigate(&instructions);
-
-    (
-        final_position_1.manhattan_distance(),
-        final_position_2.manhattan_distance()
-    )
+use crate::error::{lines, AocError, Line};
+use crate::solution::{Answer, Solution};
+use crate::year2020::day12::part1::Pose;
+
+pub struct NavigationInstructions {
+    instructions: Vec<Instruction>
+}
+
+impl Solution for NavigationInstructions {
+    fn parse(input: &str) -> Result<NavigationInstructions, AocError> {
+        Ok(NavigationInstructions {
+            instructions: load_instructions(input)?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(part1::navigate(Pose::new(), &self.instructions)
+            .manhattan_distance()
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(part2::Ship::new(part2::Waypoint::new(10, 1))
+            .navigate(&self.instructions)
+            .manhattan_distance()
+            .into())
+    }
 }
 
 mod part1 {
-    use crate::day12::Point;
-    use crate::day12::Instruction;
-    use crate::day12::Action;
+    use crate::year2020::day12::Point;
+    use crate::year2020::day12::Instruction;
+    use crate::year2020::day12::Action;
 
     #[derive(Debug, Copy, Clone)]
     pub enum Direction {
@@ -69,7 +83,7 @@ mod part1 {
         }
     }
     
-    pub fn navigate(start: Pose, instructions: &Vec<Instruction>) -> Point {
+    pub fn navigate(start: Pose, instructions: &[Instruction]) -> Point {
         instructions
             .iter()
             .fold(start, |pose,instruction| move_ship(pose, *instruction))
@@ -105,9 +119,9 @@ mod part1 {
 }
 
 mod part2 {
-    use crate::day12::Point;
-    use crate::day12::Instruction;
-    use crate::day12::Action;
+    use crate::year2020::day12::Point;
+    use crate::year2020::day12::Instruction;
+    use crate::year2020::day12::Action;
 
     #[derive(Debug, Copy, Clone)]   
     pub struct Waypoint {
@@ -118,8 +132,8 @@ mod part2 {
     impl Waypoint {
         pub fn new(x: i32, y: i32) -> Waypoint {
             Waypoint {
-                x: x,
-                y: y
+                x,
+                y
             }
         }
 
@@ -168,11 +182,11 @@ mod part2 {
         pub fn new(waypoint: Waypoint) -> Ship {
             Ship {
                 position: Point::empty(),
-                waypoint: waypoint
+                waypoint
             }
         }
 
-        pub fn navigate(self, instructions: &Vec<Instruction>) -> Point {
+        pub fn navigate(self, instructions: &[Instruction]) -> Point {
             instructions
                 .iter()
                 .fold(self, |ship,instruction| ship.make_move(*instruction))
@@ -219,7 +233,7 @@ impl Point {
     }
 
     pub fn manhattan_distance(&self) -> usize {
-        (&self.x.abs() + &self.y.abs()) as usize
+        (self.x.abs() + self.y.abs()) as usize
     }
 }
 
@@ -241,11 +255,11 @@ pub enum Action {
 }
 
 impl Instruction {
-    fn parse(text: &str) -> Instruction {
+    fn parse(line: &Line) -> Result<Instruction, AocError> {
 
-        let text = text.trim();
-        let letter = text.chars().next().unwrap();
-        let number = text[1..].parse().unwrap();
+        let text = line.text.trim();
+        let letter = text.chars().next().ok_or_else(|| line.missing("an action"))?;
+        let number: u32 = line.parse(&text[letter.len_utf8()..], "a number")?;
 
         let action = match letter {
             'F' => Action::Forward,
@@ -255,43 +269,43 @@ impl Instruction {
             'W' => Action::West,
             'L' => Action::Left,
             'R' => Action::Right,
-            _ => panic!("Unexpected action")
+            _ => return Err(line.error(text, format!("unexpected action {:?}", letter)))
         };
 
         let magnitude = match action {
-            Action::Left | Action::Right => number / 90,
+            Action::Left | Action::Right => {
+                if !number.is_multiple_of(90) {
+                    return Err(line.error(&text[1..], "expected a multiple of 90 degrees"));
+                }
+                number / 90
+            },
             _ => number
         };
 
-        Instruction {
-            action: action, 
-            magnitude: magnitude
-        }
+        Ok(Instruction {
+            action, 
+            magnitude
+        })
     }
 }
 
 
-fn load_instructions(input: &str) -> Vec<Instruction> {
-    input
-        .lines()
-        .map(Instruction::parse)
+fn load_instructions(input: &str) -> Result<Vec<Instruction>, AocError> {
+    lines(input)
+        .map(|line| Instruction::parse(&line))
         .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day12::*;
+    use crate::year2020::day12::*;
+    use crate::year2020::examples;
 
     #[test]
     fn test_navigate_part1() {
-        let input = 
-            r"F10
-            N3
-            F7
-            R90
-            F11";
-
-        let instructions = load_instructions(&input);
+        let input = examples::input(12, "example");
+
+        let instructions = load_instructions(input).unwrap();
         let final_position = part1::navigate(Pose::new(), &instructions);
     
         assert_eq!(25, final_position.manhattan_distance());
@@ -299,16 +313,20 @@ mod tests {
 
     #[test]
     fn test_navigate_part2() {
-        let input = 
-            r"F10
-            N3
-            F7
-            R90
-            F11";
-
-        let instructions = load_instructions(&input);
+        let input = examples::input(12, "example");
+
+        let instructions = load_instructions(input).unwrap();
         let final_position = part2::Ship::new(part2::Waypoint::new(10, 1)).navigate(&instructions);
     
         assert_eq!(286, final_position.manhattan_distance());
     }
+
+    #[test]
+    fn test_load_instructions_reports_location() {
+        let input = "F10\nN3\nR45";
+
+        let err = load_instructions(input).unwrap_err();
+
+        assert_eq!(r#"line 3, column 2: expected a multiple of 90 degrees in "R45""#, err.to_string());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,160 @@
+use crate::error::{lines, AocError, Line};
+use crate::solution::{Answer, Solution};
+
+pub struct BusNotes {
+    notes: part1::Notes,
+    timetable: part2::Timetable
+}
+
+impl Solution for BusNotes {
+    fn parse(input: &str) -> Result<BusNotes, AocError> {
+        Ok(BusNotes {
+            notes: part1::Notes::parse(input)?,
+            timetable: part2::Timetable::parse(input)?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.notes.calculate().into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(self.timetable.find_earliest_matching_timestamp()?.into())
+    }
+}
+
+// The notes are the earliest departure time on the first line, then the bus ids
+// (or x for out of service) on the second
+fn parse_notes(input: &str) -> Result<(u32, Vec<Option<usize>>), AocError> {
+    let mut lines = lines(input);
+
+    let first = lines.next().ok_or_else(|| AocError::new("expected the earliest departure time"))?;
+    let timestamp = first.parse(first.text, "the earliest departure time")?;
+
+    let second = lines.next().ok_or_else(|| AocError::new("expected the bus ids on line 2"))?;
+    let buses: Vec<Option<usize>> = second
+        .text
+        .trim()
+        .split(',')
+        .map(|s| parse_bus(&second, s))
+        .collect::<Result<_, _>>()?;
+
+    if buses.iter().all(|bus| bus.is_none()) {
+        return Err(second.error(second.text, "expected at least one bus id"));
+    }
+
+    Ok((timestamp, buses))
+}
+
+fn parse_bus(line: &Line, s: &str) -> Result<Option<usize>, AocError> {
+    match s.trim() {
+        "x" => Ok(None),
+        _ => {
+            let id = line.parse(s, "a bus id or x")?;
+            if id == 0 {
+                return Err(line.error(s, "bus ids start at 1"));
+            }
+            Ok(Some(id))
+        }
+    }
+}
+
+mod part1 {
+    use crate::year2020::day13::parse_notes;
+    use crate::error::AocError;
+
+    pub struct Notes {
+        timestamp: u32,
+        buses: Vec<u32>
+    }
+
+    impl Notes {
+        pub fn parse(input: &str) -> Result<Notes, AocError> {
+            let (timestamp, buses) = parse_notes(input)?;
+            Ok(Notes {
+                timestamp,
+                buses: buses
+                    .into_iter()
+                    .flatten()
+                    .map(|id| id as u32)
+                    .collect()
+            })
+        }
+
+        pub fn calculate(&self) -> u32 {
+            let earliest_bus = self
+                .buses
+                .iter()
+                .map(|b| (b, b - (self.timestamp % b)))
+                .min_by(|b1, b2| b1.1.cmp(&b2.1))
+                .unwrap();
+
+            earliest_bus.0 * earliest_bus.1
+        }
+    }
+}
+
+mod part2 {
+    use crate::cancel;
+    use crate::year2020::day13::parse_notes;
+    use crate::error::AocError;
+
+    #[derive(Debug)]   
+    pub struct Timetable {
+        pub buses: Vec<Bus>
+    }
+
+    #[derive(Debug)]   
+    pub struct Bus {
+        id: usize,
+        offset: usize
+    }
+
+    impl Timetable {
+        pub fn parse(input: &str) -> Result<Timetable, AocError> {
+            let (_, buses) = parse_notes(input)?;
+            Ok(Timetable {
+                buses: buses
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(i, id)| id.map(|id| Bus {id, offset: i}))
+                    .collect()
+            })
+        }
+
+        // As each successive bus id is matched, multiply the timestamp step (dt)
+        // by that bus id. Bus ids that aren't coprime may never line up, so the
+        // search can be cancelled.
+        pub fn find_earliest_matching_timestamp(&self) -> Result<usize, AocError> {
+            let mut t = self.buses[0].id;
+            let mut dt = t;
+            for bus in self.buses.iter().skip(1) {
+                let mut i = 0;
+                while !(t + bus.offset).is_multiple_of(bus.id) {
+                    cancel::check_every(i)?;
+                    t += dt;
+                    i += 1;
+                }
+                dt *= bus.id;
+            }
+            Ok(t)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::year2020::day13::*;
+        use crate::year2020::examples;
+    
+        #[test]
+        fn test_find_earliest_matching_timestamp() {
+            let input = examples::input(13, "example");
+            let timetable = part2::Timetable::parse(input).unwrap();
+    
+            let result = timetable.find_earliest_matching_timestamp().unwrap();
+            
+            assert_eq!(1068781, result);
+        }
+    }    
+}
+
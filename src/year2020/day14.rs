@@ -0,0 +1,320 @@
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+pub struct InitializationProgram {
+    program: Vec<common::Instruction>
+}
+
+impl Solution for InitializationProgram {
+    fn parse(input: &str) -> Result<InitializationProgram, AocError> {
+        Ok(InitializationProgram {
+            program: common::parse_program(input)?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(part1::Computer::new().run(&self.program)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(part2::Computer::new().run(&self.program)?.into())
+    }
+}
+
+mod common {
+    use crate::error::{lines, AocError, Line};
+    use std::fmt;
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum Instruction {
+        SetMask(Mask),
+        SetMemory(Write)
+    }
+
+    impl Instruction {
+        pub fn parse(line: &Line) -> Result<Instruction, AocError> {
+            let text = line.text.trim();
+            let mut parts = text.split(" = ");
+            let left = parts.next().unwrap();
+            let right = line.next(&mut parts, "\" = \" followed by a value")?;
+
+            if left == "mask" {
+                if right.len() != 36 {
+                    return Err(line.error(right, format!("expected a 36 bit mask, found {} bits", right.len())));
+                }
+                if let Some((i, c)) = right.char_indices().find(|(_, c)| !"01X".contains(*c)) {
+                    return Err(line.error(&right[i..], format!("expected 0, 1 or X, found {:?}", c)));
+                }
+                Ok(Instruction::SetMask(Mask {
+                    zeros: u64::from_str_radix(&right.replace('X',"1"), 2).unwrap(),
+                    ones: u64::from_str_radix(&right.replace('X',"0"), 2).unwrap(),
+                    floats: u64::from_str_radix(&right.replace('1',"0").replace('X',"1"), 2).unwrap(),
+                }))
+            } else if left.starts_with("mem[") && left.ends_with(']') {
+                let value: u64 = line.parse(right, "a value")?;
+                // Memory holds 36 bit values, which keeps part 2's sum from overflowing
+                if value >= 1 << 36 {
+                    return Err(line.error(right, "expected a 36 bit value"));
+                }
+                Ok(Instruction::SetMemory(Write {
+                    location: line.parse(&left[4..left.len()-1], "a memory address")?, 
+                    value
+                }))
+            } else {
+                Err(line.error(left, "expected mask or mem[<address>]"))
+            }
+        }
+    }
+
+    // In the puzzle's own format, so a printed program parses back the same
+    impl fmt::Display for Instruction {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Instruction::SetMask(mask) => {
+                    let bits: String = (0..36).rev().map(|bit| {
+                        if mask.floats & (1 << bit) != 0 {
+                            'X'
+                        } else if mask.ones & (1 << bit) != 0 {
+                            '1'
+                        } else {
+                            '0'
+                        }
+                    }).collect();
+                    write!(f, "mask = {}", bits)
+                },
+                Instruction::SetMemory(write) => write!(f, "mem[{}] = {}", write.location, write.value)
+            }
+        }
+    }
+
+    pub fn parse_program(program: &str) -> Result<Vec<Instruction>, AocError> {
+        lines(program)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| Instruction::parse(&line))
+            .collect()
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub struct Mask {
+        pub zeros: u64,
+        pub ones: u64,
+        pub floats: u64
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub struct Write {
+        pub location: u64,
+        pub value: u64
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::year2020::day14::*;
+        use proptest::prelude::*;
+
+        #[test]
+        fn test_parse_program_reports_location() {
+            let input = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = eleven";
+
+            let err = common::parse_program(input).unwrap_err();
+
+            assert_eq!(r#"line 2, column 10: expected a value, found "eleven" in "mem[8] = eleven""#, err.to_string());
+        }
+
+        #[test]
+        fn test_parse_program_rejects_wide_values() {
+            let input = "mask = 00000000000000000000000000000000000X\nmem[0] = 18446744073709551615";
+
+            let err = common::parse_program(input).unwrap_err();
+
+            assert_eq!(r#"line 2, column 10: expected a 36 bit value in "mem[0] = 18446744073709551615""#, err.to_string());
+        }
+
+        fn instruction_text() -> impl Strategy<Value = String> {
+            prop_oneof![
+                "[01X]{36}".prop_map(|mask| format!("mask = {}", mask)),
+                (any::<u64>(), 0..1_u64 << 36).prop_map(|(location, value)| format!("mem[{}] = {}", location, value))
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn prop_parsed_programs_print_back(program in proptest::collection::vec(instruction_text(), 0..20)) {
+                let instructions = common::parse_program(&program.join("\n")).unwrap();
+
+                let printed: Vec<String> = instructions.iter().map(|i| i.to_string()).collect();
+
+                prop_assert_eq!(program, printed);
+            }
+        }
+    }
+}
+
+mod part1 {
+    use crate::cancel;
+    use crate::error::AocError;
+    use crate::year2020::day14::common;
+    use std::collections::HashMap;
+    use common::Instruction::*;
+    use common::*;
+
+    #[derive(Debug)]   
+    pub struct Computer {
+        memory: HashMap<u64, u64>,
+        mask: Mask
+    }
+
+    impl Computer {
+
+        pub fn new() -> Computer {
+            Computer {
+                memory: HashMap::new(),
+                mask: Mask { zeros: 0, ones: 0, floats: 0 }
+            }
+        }
+
+        pub fn run(&mut self, instructions: &[Instruction]) -> Result<u64, AocError> {
+            for (n, i) in instructions.iter().enumerate() {
+                cancel::check_every(n)?;
+                match *i {
+                    SetMask(mask) => self.set_mask(mask),
+                    SetMemory(write) => self.set_memory(write.location, write.value)
+                };
+            }
+
+            Ok(self.get_memory_sum())
+        }
+
+        fn set_mask(&mut self, mask: Mask) {
+            self.mask = mask;
+        }
+
+        fn set_memory(&mut self, location: u64, value: u64) {
+            let value = value & self.mask.zeros;
+            let value = value | self.mask.ones;
+            self.memory.insert(location, value);
+        }
+
+        fn get_memory_sum(&self) -> u64 {
+            self.memory.values().sum()
+        } 
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::year2020::day14::*;
+    
+        #[test]
+        fn test_program_1() {
+            let input = r"
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0";
+
+            let result = part1::Computer::new().run(&common::parse_program(input).unwrap()).unwrap();
+            
+            assert_eq!(165, result);
+        }
+    }
+}
+
+mod part2 {
+    use crate::cancel;
+    use crate::error::AocError;
+    use crate::year2020::day14::common;
+    use std::collections::HashMap;
+    use common::Instruction::*;
+    use common::*;
+
+    #[derive(Debug)]   
+    pub struct Computer {
+        memory: HashMap<u64, u64>,
+        mask: Mask,
+        mask_combinations: Vec<u64>
+    }
+
+    impl Computer {
+
+        pub fn new() -> Computer {
+            Computer {
+                memory: HashMap::new(),
+                mask: Mask { zeros: 0, ones: 0, floats: 0 },
+                mask_combinations: Vec::new()
+            }
+        }
+
+        // A mask with many floating bits writes to a great many addresses, so the time
+        // is checked as they are worked out and written to
+        pub fn run(&mut self, instructions: &[Instruction]) -> Result<u64, AocError> {
+            for i in instructions {
+                match *i {
+                    SetMask(mask) => self.set_mask(mask)?,
+                    SetMemory(write) => self.set_memory(write.location, write.value)?
+                };
+            }
+
+            Ok(self.get_memory_sum())
+        }
+
+        fn set_mask(&mut self, mask: Mask) -> Result<(), AocError> {
+            self.mask = mask;
+            self.mask_combinations = Computer::generate_combos(mask.floats)?;
+            Ok(())
+        }
+
+        fn generate_combos(mask: u64) -> Result<Vec<u64>, AocError> {
+            let mut results: Vec<u64> = vec![0];
+            for i in 0..36 {
+                let num = 1 << i;
+                if num & mask > 0 {
+                    for j in 0..results.len() {
+                        cancel::check_every(j)?;
+                        results.push(results[j] | num);
+                    }
+                }
+            }
+            Ok(results)
+        }    
+
+        fn set_memory(&mut self, location: u64, value: u64) -> Result<(), AocError> {
+
+            let location = location & (self.mask.floats ^ 0b111111111111111111111111111111111111_u64);
+            let location = location | self.mask.ones;
+
+            for (i, a) in self.mask_combinations.iter().enumerate() {
+                cancel::check_every(i)?;
+                self.memory.insert(location | a, value);
+            }
+
+            Ok(())
+        }
+
+        fn get_memory_sum(&self) -> u64 {
+            self.memory.values().sum()
+        } 
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::year2020::day14::*;
+    
+        #[test]
+        fn test_generate_combos() {
+            let results = part2::Computer::generate_combos(10);
+            assert_eq!(Ok(vec![0, 2, 8, 10]), results);
+        }
+
+        #[test]
+        fn test_program_2() {
+            let input = r"
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1";
+
+            let result = part2::Computer::new().run(&common::parse_program(input).unwrap()).unwrap();
+            assert_eq!(208, result);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,385 @@
+use crate::cancel;
+use crate::error::{lines, AocError};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+pub struct StartingNumbers {
+    numbers: Vec<usize>
+}
+
+impl Solution for StartingNumbers {
+    fn parse(input: &str) -> Result<StartingNumbers, AocError> {
+        let line = lines(input).next().ok_or_else(|| AocError::new("expected starting numbers"))?;
+
+        Ok(StartingNumbers {
+            numbers: line
+                .text
+                .split(',')
+                .map(|n| line.parse(n, "a number"))
+                .collect::<Result<_, _>>()?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(solve(&self.numbers, 2020)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(solve(&self.numbers, 30000000)?.into())
+    }
+}
+
+// Numbers below this are looked up in an array, which is what makes thirty million
+// turns quick. Every number spoken after the starting ones is an age, so below the
+// number of turns; only starting numbers or very long games need the map.
+const DENSE_LIMIT: usize = 1 << 26;
+
+const CHECKPOINT_HEADER: &str = "memory game";
+
+fn solve(starting_numbers: &[usize], n: usize) -> Result<usize, AocError> {
+    if n == 0 || starting_numbers.is_empty() {
+        return Err(AocError::new("the game needs starting numbers and at least one turn"));
+    }
+    if n > u32::MAX as usize {
+        return Err(AocError::new(format!("can only play up to {} turns", u32::MAX)));
+    }
+
+    let mut game = MemoryGame::with_capacity(starting_numbers, n);
+    game.play_to(n)?;
+
+    Ok(game.last.unwrap_or(0))
+}
+
+// The game as an iterator over the numbers spoken, giving (turn, number, age) for each
+// turn from 1, where age is how many turns ago the number was last spoken, or 0 if it
+// is new. Each age is the next number spoken. The game can be saved to a checkpoint
+// and resumed from it later.
+pub struct MemoryGame {
+    starting_numbers: Vec<usize>,
+    turn: usize,
+    last: Option<usize>,
+    next: usize,
+    distinct: usize,
+    memory: Memory,
+    // Each number in the order they were first spoken, with the turn it was spoken
+    // on. Only appended to when a number is new, which keeps the game fast.
+    firsts: Vec<(usize, u32)>
+}
+
+impl MemoryGame {
+    pub fn new(starting_numbers: &[usize]) -> MemoryGame {
+        MemoryGame::with_capacity(starting_numbers, 0)
+    }
+
+    // A game with room for the numbers spoken in the first turns set aside up front
+    pub fn with_capacity(starting_numbers: &[usize], turns: usize) -> MemoryGame {
+        MemoryGame {
+            starting_numbers: starting_numbers.to_vec(),
+            turn: 0,
+            last: None,
+            next: 0,
+            distinct: 0,
+            memory: Memory::new(turns.min(DENSE_LIMIT)),
+            firsts: Vec::new()
+        }
+    }
+
+    // The number of turns played so far
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    pub fn last_spoken(&self) -> Option<usize> {
+        self.last
+    }
+
+    // The last turn number was spoken on, if it has been
+    pub fn last_turn(&self, number: usize) -> Option<usize> {
+        Some(self.memory.get(number) as usize).filter(|turn| *turn > 0)
+    }
+
+    // Play on until turn, stopping with a timed out error if it is taking too long
+    pub fn play_to(&mut self, turn: usize) -> Result<(), AocError> {
+        let turn = turn.min(u32::MAX as usize);
+        while self.turn < turn {
+            cancel::check_every(self.turn)?;
+            self.speak();
+        }
+        Ok(())
+    }
+
+    // The turn number is first spoken on, playing on no further than turn limit, or
+    // None if it isn't spoken by then
+    pub fn first_turn(&mut self, number: usize, limit: usize) -> Result<Option<usize>, AocError> {
+        if self.last_turn(number).is_some() {
+            let first = self.firsts.iter().find(|(spoken, _)| *spoken == number).map(|(_, turn)| *turn as usize);
+            return Ok(first.filter(|turn| *turn <= limit));
+        }
+
+        while self.turn < limit {
+            cancel::check_every(self.turn)?;
+            match self.next() {
+                Some((turn, spoken, _)) if spoken == number => return Ok(Some(turn)),
+                Some(_) => (),
+                None => break
+            }
+        }
+
+        Ok(None)
+    }
+
+    // How many different numbers have been spoken by turn, playing on to it if needed
+    pub fn distinct_by(&mut self, turn: usize) -> Result<usize, AocError> {
+        if turn < self.turn {
+            return Err(AocError::new(format!("the game is already at turn {}, past turn {}", self.turn, turn)));
+        }
+
+        self.play_to(turn)?;
+        Ok(self.distinct)
+    }
+
+    // Play the next turn, which must still fit in 32 bits
+    fn speak(&mut self) -> (usize, usize, usize) {
+        self.turn += 1;
+        let number = self.starting_numbers.get(self.turn - 1).copied().unwrap_or(self.next);
+        let age = match self.memory.speak(number, self.turn as u32) {
+            0 => {
+                self.distinct += 1;
+                self.firsts.push((number, self.turn as u32));
+                0
+            },
+            before => self.turn - before as usize
+        };
+
+        self.last = Some(number);
+        self.next = age;
+        (self.turn, number, age)
+    }
+
+    // The state of the game as text: a header, the starting numbers, the turn reached
+    // and the next number, then every number spoken with the first and last turns it
+    // was spoken on, one per line
+    pub fn checkpoint(&self) -> String {
+        let starting: Vec<String> = self.starting_numbers.iter().map(|n| n.to_string()).collect();
+        let mut text = format!(
+            "{}\nstarting {}\nturn {}\nnext {}\n",
+            CHECKPOINT_HEADER,
+            starting.join(","),
+            self.turn,
+            self.next);
+
+        let mut firsts = self.firsts.clone();
+        firsts.sort_unstable();
+        for (number, first) in firsts {
+            text.push_str(&format!("{} {} {}\n", number, first, self.memory.get(number)));
+        }
+
+        text
+    }
+
+    pub fn resume(checkpoint: &str) -> Result<MemoryGame, AocError> {
+        let mut lines = lines(checkpoint).filter(|line| !line.text.trim().is_empty());
+
+        let mut field = |name: &str| -> Result<&str, AocError> {
+            let line = lines.next().ok_or_else(|| AocError::new(format!("expected {} in the checkpoint", name)))?;
+            match line.text.trim().strip_prefix(name) {
+                Some(value) => Ok(value.trim()),
+                None => Err(line.error(line.text, format!("expected {}", name)))
+            }
+        };
+
+        field(CHECKPOINT_HEADER)?;
+        let starting = field("starting")?;
+        let turn = field("turn")?;
+        let next = field("next")?;
+
+        let number = |text: &str| -> Result<usize, AocError> {
+            text.parse().map_err(|_| AocError::within(checkpoint, text, format!("expected a number, found {:?}", text)))
+        };
+
+        let starting_numbers = starting.split(',').map(|n| number(n.trim())).collect::<Result<Vec<_>, _>>()?;
+        let turn = number(turn)?;
+        let mut game = MemoryGame::with_capacity(&starting_numbers, turn);
+        game.turn = turn;
+        game.next = number(next)?;
+
+        for line in lines {
+            let mut parts = line.text.split_whitespace();
+            let spoken: usize = line.parse(line.next(&mut parts, "a number")?, "a number")?;
+            let first: u32 = line.parse(line.next(&mut parts, "the turn it was first spoken on")?, "a turn")?;
+            let on: u32 = line.parse(line.next(&mut parts, "the turn it was last spoken on")?, "a turn")?;
+            if first == 0 || first > on || on as usize > turn {
+                return Err(line.error(line.text, format!("expected two turns in order from 1 to {}", turn)));
+            }
+            if game.memory.speak(spoken, on) != 0 {
+                return Err(line.error(line.text, format!("{} is in the checkpoint twice", spoken)));
+            }
+            game.firsts.push((spoken, first));
+            if on as usize == turn {
+                game.last = Some(spoken);
+            }
+            game.distinct += 1;
+        }
+
+        game.firsts.sort_unstable_by_key(|(_, first)| *first);
+
+        if turn > 0 && game.last.is_none() {
+            return Err(AocError::new(format!("nothing was spoken on turn {} in the checkpoint", turn)));
+        }
+
+        Ok(game)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        fs::write(path, self.checkpoint()).map_err(|err| AocError::new(format!("cannot write {}: {}", path.display(), err)))
+    }
+
+    pub fn load(path: &Path) -> Result<MemoryGame, AocError> {
+        let checkpoint = fs::read_to_string(path).map_err(|err| AocError::new(format!("cannot read {}: {}", path.display(), err)))?;
+        MemoryGame::resume(&checkpoint)
+    }
+}
+
+// The game ends once turns no longer fit in the 32 bits the memory keeps them in
+impl Iterator for MemoryGame {
+    type Item = (usize, usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize, usize)> {
+        if self.turn >= u32::MAX as usize {
+            return None;
+        }
+
+        Some(self.speak())
+    }
+}
+
+// The turn each number was last spoken on, from 1, with 0 for never. The array grows
+// as the numbers spoken do, up to DENSE_LIMIT; much larger numbers than have been
+// spoken so far, such as a huge starting number, go in the map.
+struct Memory {
+    dense: Vec<u32>,
+    sparse: HashMap<usize, u32>
+}
+
+impl Memory {
+    fn new(dense: usize) -> Memory {
+        Memory {
+            dense: vec![0; dense],
+            sparse: HashMap::new()
+        }
+    }
+
+    fn get(&self, number: usize) -> u32 {
+        match self.dense.get(number) {
+            Some(turn) => *turn,
+            None => self.sparse.get(&number).copied().unwrap_or(0)
+        }
+    }
+
+    // Record number as spoken on turn, giving the turn it was spoken on before
+    fn speak(&mut self, number: usize, turn: u32) -> u32 {
+        if let Some(last) = self.dense.get_mut(number) {
+            return std::mem::replace(last, turn);
+        }
+
+        if number < DENSE_LIMIT && number <= 2 * self.dense.len() + 1024 {
+            self.grow(number);
+            return std::mem::replace(&mut self.dense[number], turn);
+        }
+
+        self.sparse.insert(number, turn).unwrap_or(0)
+    }
+
+    // Make the array big enough for number, moving anything in the map that now fits
+    fn grow(&mut self, number: usize) {
+        let size = (number + 1).next_power_of_two().min(DENSE_LIMIT);
+        self.dense.resize(size, 0);
+
+        let moved: Vec<usize> = self.sparse.keys().copied().filter(|n| *n < size).collect();
+        for n in moved {
+            if let Some(turn) = self.sparse.remove(&n) {
+                self.dense[n] = turn;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day15::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Ok(436), solve(&[0, 3, 6], 2020));
+        assert_eq!(Ok(1), solve(&[1, 3, 2], 2020));
+        assert_eq!(Ok(1836), solve(&[3, 1, 2], 2020));
+        assert_eq!(Ok(0), solve(&[0, 3, 6], 4));
+        assert_eq!(Ok(3), solve(&[0, 3, 6], 2));
+        assert!(solve(&[0, 3, 6], 0).is_err());
+    }
+
+    #[test]
+    fn test_game_yields_turn_number_and_age() {
+        let turns: Vec<(usize, usize, usize)> = MemoryGame::new(&[0, 3, 6]).take(10).collect();
+
+        assert_eq!(vec![
+            (1, 0, 0), (2, 3, 0), (3, 6, 0), (4, 0, 3), (5, 3, 3),
+            (6, 3, 1), (7, 1, 0), (8, 0, 4), (9, 4, 0), (10, 0, 2)
+        ], turns);
+    }
+
+    #[test]
+    fn test_large_numbers_go_in_the_map() {
+        let mut game = MemoryGame::new(&[usize::MAX, 7, usize::MAX, 1 << 40]);
+
+        assert_eq!(Some((4, 1 << 40, 0)), game.nth(3));
+        assert_eq!(Some(3), game.last_turn(usize::MAX));
+        assert_eq!(
+            MemoryGame::new(&[2, 1, 3]).nth(2019),
+            MemoryGame::with_capacity(&[2, 1, 3], 2020).nth(2019));
+        assert_eq!(Ok(2), solve(&[usize::MAX, 7, usize::MAX], 4));
+    }
+
+    #[test]
+    fn test_queries() {
+        let mut game = MemoryGame::new(&[0, 3, 6]);
+
+        assert_eq!(Ok(Some(9)), game.first_turn(4, 100));
+        assert_eq!(Ok(Some(2)), game.first_turn(3, 100));
+        assert_eq!(Ok(Some(1)), game.first_turn(0, 100));
+        assert_eq!(Ok(5), game.distinct_by(10));
+        assert_eq!(10, game.turn());
+        assert_eq!(Ok(None), game.first_turn(1000, 20));
+        assert_eq!(Ok(None), game.first_turn(4, 5));
+        assert!(game.distinct_by(10).is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_and_resume() {
+        let mut game = MemoryGame::new(&[0, 3, 6]);
+        game.play_to(10).unwrap();
+
+        let checkpoint = game.checkpoint();
+        assert_eq!("memory game\nstarting 0,3,6\nturn 10\nnext 2\n0 1 10\n1 7 7\n3 2 6\n4 9 9\n6 3 3\n", checkpoint);
+
+        let mut resumed = MemoryGame::resume(&checkpoint).unwrap();
+        assert_eq!(Ok(Some(2)), MemoryGame::resume(&checkpoint).unwrap().first_turn(3, 100));
+        assert_eq!(Some(0), resumed.last_spoken());
+        assert_eq!(game.nth(2009), resumed.nth(2009));
+        assert_eq!(Some(436), resumed.last_spoken());
+        assert_eq!(Ok(game.distinct_by(3000).unwrap()), resumed.distinct_by(3000));
+
+        // Resuming part way through the starting numbers carries on with them
+        let mut early = MemoryGame::new(&[0, 3, 6]);
+        early.play_to(2).unwrap();
+        assert_eq!(Some((3, 6, 0)), MemoryGame::resume(&early.checkpoint()).unwrap().next());
+
+        let err = MemoryGame::resume("memory game\nstarting 0,3,6\nturn 3\nnext 0\n0 1 1\n3 2 x\n").err().unwrap();
+        assert_eq!(r#"line 6, column 5: expected a turn, found "x" in "3 2 x""#, err.to_string());
+        assert!(MemoryGame::resume("memory game\nstarting 0,3,6\nturn 3\nnext 0\n0 1 1\n3 2 4\n").is_err());
+        assert!(MemoryGame::resume("memory game\nstarting 0,3,6\nturn 3\nnext 0\n0 1 1\n3 3 2\n").is_err());
+        assert!(MemoryGame::resume("starting 0,3,6\n").is_err());
+    }
+}
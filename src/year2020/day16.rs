@@ -1,16 +1,25 @@
-use crate::day16::puzzle::Puzzle;
-use std::fs;
-
-pub fn run() -> (u32, u64) {
-    let input = fs::read_to_string("input/day16.txt").unwrap();
-    let puzzle = Puzzle::parse(&input);
-    (
-        puzzle.calculate_ticket_scanning_error_rate(),
-        puzzle.calculate_part2()
-    )
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+pub use crate::year2020::day16::puzzle::Puzzle;
+
+impl Solution for Puzzle {
+    fn parse(input: &str) -> Result<Puzzle, AocError> {
+        Puzzle::parse(input)
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(self.calculate_ticket_scanning_error_rate().into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(self.calculate_part2()?.into())
+    }
 }
 
 mod puzzle {
+    use crate::cancel;
+    use crate::error::{AocError, Line};
+    use crate::input::{next_record, records, Record};
     use bimap::BiMap;
     use std::ops::Range;
 
@@ -22,45 +31,62 @@ mod puzzle {
     }
     
     impl Puzzle {
-        pub fn parse(input: &str) -> Puzzle {
-            fn parse_rules(section: &str) -> Vec<TicketFieldRule>  {
+        pub fn parse(input: &str) -> Result<Puzzle, AocError> {
+            fn parse_rules(section: &Record) -> Result<Vec<TicketFieldRule>, AocError> {
                 section
-                    .lines()
+                    .lines
+                    .iter()
                     .map(TicketFieldRule::parse_rule)
                     .collect()
             }
 
-            fn parse_my_ticket(section: &str) -> Vec<u32> {
-                section
-                    .lines()
-                    .skip(1)
-                    .map(parse_ticket)
-                    .next()
-                    .unwrap()
+            fn parse_my_ticket(section: &Record) -> Result<Vec<u32>, AocError> {
+                let line = section
+                    .body()
+                    .first()
+                    .ok_or_else(|| section.lines[0].missing("your ticket on the next line"))?;
+                parse_ticket(line)
             }
 
-            fn parse_ticket(text: &str) -> Vec<u32> {
-                text
-                    .split(",")
-                    .map(|s| s.parse().unwrap())
+            fn parse_ticket(line: &Line) -> Result<Vec<u32>, AocError> {
+                line
+                    .text
+                    .split(',')
+                    .map(|s| line.parse(s, "a number"))
                     .collect()
             }
 
-            fn parse_nearby_tickets(section: &str) -> Vec<Vec<u32>> {
+            fn parse_nearby_tickets(section: &Record) -> Result<Vec<Vec<u32>>, AocError> {
                 section
-                    .lines()
-                    .skip(1)
+                    .body()
+                    .iter()
                     .map(parse_ticket)
                     .collect()
             }
 
-            let mut sections = input.split("\n\n");
+            let mut sections = records(input).into_iter();
+
+            let puzzle = Puzzle {
+                rules: parse_rules(&next_record(&mut sections, "a section of rules")?)?,
+                my_ticket: parse_my_ticket(&next_record(&mut sections, "a section of your ticket")?)?,
+                nearby_tickets: parse_nearby_tickets(&next_record(&mut sections, "a section of nearby tickets")?)?,
+            };
 
-            Puzzle {
-                rules: parse_rules(sections.next().unwrap()),
-                my_ticket: parse_my_ticket(sections.next().unwrap()),
-                nearby_tickets: parse_nearby_tickets(sections.next().unwrap()),
+            if puzzle.my_ticket.len() != puzzle.rules.len() {
+                return Err(AocError::new(format!(
+                    "expected your ticket to have {} fields, found {}",
+                    puzzle.rules.len(),
+                    puzzle.my_ticket.len())));
             }
+
+            if let Some(ticket) = puzzle.nearby_tickets.iter().find(|t| t.len() != puzzle.rules.len()) {
+                return Err(AocError::new(format!(
+                    "expected every nearby ticket to have {} fields, found {:?}",
+                    puzzle.rules.len(),
+                    ticket)));
+            }
+
+            Ok(puzzle)
         }
 
         pub fn calculate_ticket_scanning_error_rate(&self) -> u32 {
@@ -71,7 +97,7 @@ mod puzzle {
                 .sum()
         }
 
-        pub fn calculate_part2(&self) -> u64 {
+        pub fn calculate_part2(&self) -> Result<u64, AocError> {
             // Compile list of valid tickets
             let mut valid_tickets: Vec<_> = self
                 .nearby_tickets
@@ -97,10 +123,11 @@ mod puzzle {
             // Try repeatedly looping through position/rule combinations successively matching
             // rule to position where only 1 rule is valid
             while identified_rules.len() < self.rules.len() {
+                cancel::check()?;
 
                 let mut progress = false;
 
-                for p in 0..self.my_ticket.len() {
+                for (p, numbers) in numbers_by_position.iter().enumerate() {
 
                     if identified_rules.contains_left(&p) {
                         // skip position if we already have found a rule for this position
@@ -113,7 +140,7 @@ mod puzzle {
                         .iter()
                         .enumerate()
                         .filter(|(i, _)| !identified_rules.contains_right(i))
-                        .filter(|(_, r)| numbers_by_position[p]
+                        .filter(|(_, r)| numbers
                             .iter()
                             .all(|num| r.is_valid(*num))
                         )
@@ -128,20 +155,18 @@ mod puzzle {
                     }
                 }
 
-                for r in 0..self.rules.len() {
+                for (r, rule) in self.rules.iter().enumerate() {
                     if identified_rules.contains_right(&r) {
                         // skip rule if we already have found a position for this rule
                         continue;
                     }
 
-                    let rule = &self.rules[r];
-
                     // Find all the positions which mactch this rule
                     let matching_positions: Vec<usize> = (0..self.my_ticket.len())
                         .filter(|p| !identified_rules.contains_left(p))
-                        .filter(|p| numbers_by_position[*p as usize]
-                            .iter().
-                            all(|num| rule.is_valid(*num))
+                        .filter(|p| numbers_by_position[*p]
+                            .iter()
+                            .all(|num| rule.is_valid(*num))
                         )
                         .collect();
 
@@ -154,28 +179,28 @@ mod puzzle {
                 }
 
                 if !progress {
-                    panic!("No progress made - aborting!!");
+                    return Err(AocError::new("No progress made - aborting!!"));
                 }
             }
 
-            self
+            Ok(self
                 .rules
                 .iter()
                 .enumerate()
                 .filter(|(_, r)| r.name.starts_with("departure"))
                 .map(|(i, _)| identified_rules.get_by_right(&i).unwrap())
-                .map(|p| self.my_ticket[*p as usize] as u64)
-                .product()
+                .map(|p| self.my_ticket[*p] as u64)
+                .product())
         }
 
-        fn calculate_individual_ticket_scanning_error_rate(&self, ticket: &Vec<u32>) -> u32 {
+        fn calculate_individual_ticket_scanning_error_rate(&self, ticket: &[u32]) -> u32 {
             ticket
                 .iter()
                 .filter(|n| !self.is_number_valid(**n))
                 .sum()
         }
 
-        fn is_ticket_valid(&self, ticket: &Vec<u32>) -> bool {
+        fn is_ticket_valid(&self, ticket: &[u32]) -> bool {
             ticket
                 .iter()
                 .all(|number| self.is_number_valid(*number))
@@ -198,24 +223,24 @@ mod puzzle {
 
     impl TicketFieldRule {
 
-        pub fn parse_rule(text: &str) -> TicketFieldRule {
+        pub fn parse_rule(line: &Line) -> Result<TicketFieldRule, AocError> {
             
-            fn parse_range(text: &str) -> Range<u32> {
-                let mut range = text.split("-");
-                let from: u32 = range.next().unwrap().trim().parse().unwrap();
-                let to: u32 = range.next().unwrap().trim().parse().unwrap();
-                from..to+1
+            fn parse_range(line: &Line, text: &str) -> Result<Range<u32>, AocError> {
+                let mut range = text.split('-');
+                let from: u32 = line.parse(line.next(&mut range, "<from>-<to>")?, "a number")?;
+                let to: u32 = line.parse(line.next(&mut range, "-<to>")?, "a number")?;
+                Ok(from..to+1)
             }
 
-            let mut parts = text.split(": ");
+            let mut parts = line.text.split(": ");
             let name = parts.next().unwrap().to_string();
-            let mut ranges = parts.next().unwrap().split(" or ");
+            let mut ranges = line.next(&mut parts, "\": \" followed by two ranges")?.split(" or ");
 
-            TicketFieldRule {
-                name: name,
-                range1: parse_range(ranges.next().unwrap()),
-                range2: parse_range(ranges.next().unwrap()),
-            }
+            Ok(TicketFieldRule {
+                name,
+                range1: parse_range(line, line.next(&mut ranges, "a range")?)?,
+                range2: parse_range(line, line.next(&mut ranges, "\" or \" and a second range")?)?,
+            })
         }
 
         pub fn is_valid(&self, number: u32) -> bool {
@@ -0,0 +1,205 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+use std::ops::Range;
+
+const CYCLES: usize = 6;
+
+pub struct ConwayCubes {
+    initial_region: Grid<char>
+}
+
+impl Solution for ConwayCubes {
+    fn parse(input: &str) -> Result<ConwayCubes, AocError> {
+        Ok(ConwayCubes {
+            initial_region: parse(input)?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(part1::Space::new(&self.initial_region, CYCLES).active_after_n_cycles(CYCLES)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(part2::Space::new(&self.initial_region, CYCLES).active_after_n_cycles(CYCLES)?.into())
+    }
+}
+
+// The initial region must be square as the space is sized from its width
+fn parse(input: &str) -> Result<Grid<char>, AocError> {
+    let region = Grid::parse(input, |c| if c == '.' || c == '#' { Some(c) } else { None })?;
+
+    if region.width() != region.height() {
+        return Err(AocError::new(format!(
+            "expected a square region, found {}x{}", region.width(), region.height())));
+    }
+
+    Ok(region)
+}
+
+// The cubes start in the middle of a space padded with room for them to spread one
+// step each cycle
+fn padded(initial_region: &Grid<char>, cycles: usize) -> (usize, Grid<char>) {
+    let d = initial_region.width() + 2 * cycles;
+    let layer = Grid::from_fn(d, d, |x, y| match (x.checked_sub(cycles), y.checked_sub(cycles)) {
+        (Some(x), Some(y)) => initial_region.get(x, y).copied().unwrap_or('.'),
+        _ => '.'
+    });
+    (d, layer)
+}
+
+// The cells that can hold an active cube once cycle cycles have run on a space padded
+// for cycles: those within cycle steps of the initial region in x and y, and of its
+// layer in the other dimensions
+fn reach(width: usize, padding: usize, cycle: usize) -> (Range<usize>, Range<usize>) {
+    let d = width + 2 * padding;
+    let middle = d / 2;
+    (
+        padding.saturating_sub(cycle)..(padding + width + cycle).min(d),
+        middle.saturating_sub(cycle)..(middle + cycle + 1).min(d)
+    )
+}
+
+fn new_state(cube: char, active: usize) -> char {
+    match (cube, active) {
+        ('#', 2) | ('#', 3) | ('.', 3) => '#',
+        _ => '.'
+    }
+}
+
+// The positions next to (x, y) in a grid and (x, y) itself
+fn around<T>(grid: &Grid<T>, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+    grid.neighbours8(x, y).chain(std::iter::once((x, y)))
+}
+
+mod part1 {
+    use crate::cancel;
+    use crate::error::AocError;
+    use crate::grid::Grid;
+    use crate::year2020::day17::{around, new_state, padded, reach};
+
+    // One layer of the space for each z
+    #[derive(Debug)]
+    pub struct Space {
+        layers: Vec<Grid<char>>,
+        width: usize,
+        padding: usize,
+        cycles: usize
+    }
+
+    impl Space {
+        pub fn new(initial_region: &Grid<char>, cycles: usize) -> Space {
+            let (d, initial_layer) = padded(initial_region, cycles);
+            let mut layers = vec![Grid::new(d, d, '.'); d];
+            layers[d / 2] = initial_layer;
+
+            Space {
+                layers,
+                width: initial_region.width(),
+                padding: cycles,
+                cycles: 0
+            }
+        }
+
+        pub fn active_after_n_cycles(&mut self, n: usize) -> Result<usize, AocError> {
+            for _ in 0..n {
+                self.do_cycle()?;
+            }
+            Ok(self
+                .layers
+                .iter()
+                .flat_map(Grid::iter)
+                .filter(|c| **c == '#')
+                .count())
+        }
+
+        fn do_cycle(&mut self) -> Result<(), AocError> {
+            self.cycles += 1;
+            let (xy, zs) = reach(self.width, self.padding, self.cycles);
+
+            let mut next = self.layers.clone();
+            for z in zs {
+                cancel::check()?;
+                for (x, y) in xy.clone().flat_map(|y| xy.clone().map(move |x| (x, y))) {
+                    next[z][(x, y)] = new_state(self.layers[z][(x, y)], self.active_around(x, y, z));
+                }
+            }
+            self.layers = next;
+            Ok(())
+        }
+
+        fn active_around(&self, x: usize, y: usize, z: usize) -> usize {
+            let layer = &self.layers[z];
+            (z.saturating_sub(1)..(z + 2).min(self.layers.len()))
+                .flat_map(|z2| around(layer, x, y).map(move |(x2, y2)| (x2, y2, z2)))
+                .filter(|p| *p != (x, y, z) && self.layers[p.2][(p.0, p.1)] == '#')
+                .count()
+        }
+    }
+}
+
+mod part2 {
+    use crate::cancel;
+    use crate::error::AocError;
+    use crate::grid::Grid;
+    use crate::year2020::day17::{around, new_state, padded, reach};
+
+    // One layer of the space for each z and w
+    #[derive(Debug)]
+    pub struct Space {
+        layers: Grid<Grid<char>>,
+        width: usize,
+        padding: usize,
+        cycles: usize
+    }
+
+    impl Space {
+        pub fn new(initial_region: &Grid<char>, cycles: usize) -> Space {
+            let (d, initial_layer) = padded(initial_region, cycles);
+            let mut layers = Grid::new(d, d, Grid::new(d, d, '.'));
+            layers[(d / 2, d / 2)] = initial_layer;
+
+            Space {
+                layers,
+                width: initial_region.width(),
+                padding: cycles,
+                cycles: 0
+            }
+        }
+
+        pub fn active_after_n_cycles(&mut self, n: usize) -> Result<usize, AocError> {
+            for _ in 0..n {
+                self.do_cycle()?;
+            }
+            Ok(self
+                .layers
+                .iter()
+                .flat_map(Grid::iter)
+                .filter(|c| **c == '#')
+                .count())
+        }
+
+        fn do_cycle(&mut self) -> Result<(), AocError> {
+            self.cycles += 1;
+            let (xy, zw) = reach(self.width, self.padding, self.cycles);
+
+            let mut next = self.layers.clone();
+            for (z, w) in zw.clone().flat_map(|w| zw.clone().map(move |z| (z, w))) {
+                cancel::check()?;
+                for (x, y) in xy.clone().flat_map(|y| xy.clone().map(move |x| (x, y))) {
+                    next[(z, w)][(x, y)] = new_state(self.layers[(z, w)][(x, y)], self.active_around(x, y, z, w));
+                }
+            }
+            self.layers = next;
+            Ok(())
+        }
+
+        fn active_around(&self, x: usize, y: usize, z: usize, w: usize) -> usize {
+            let layer = &self.layers[(z, w)];
+            around(&self.layers, z, w)
+                .flat_map(|(z2, w2)| around(layer, x, y).map(move |(x2, y2)| (x2, y2, z2, w2)))
+                .filter(|p| *p != (x, y, z, w) && self.layers[(p.2, p.3)][(p.0, p.1)] == '#')
+                .count()
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::error::{lines, AocError};
+use crate::solution::{Answer, Solution};
+
+pub struct Homework {
+    expressions: String
+}
+
+impl Solution for Homework {
+    fn parse(input: &str) -> Result<Homework, AocError> {
+        // Evaluate once up front so a malformed expression is reported as a parse error
+        evaluate_expressions(input, false)?;
+        Ok(Homework {
+            expressions: input.to_string()
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(evaluate_expressions(&self.expressions, false)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(evaluate_expressions(&self.expressions, true)?.into())
+    }
+}
+
+fn evaluate_expressions(input: &str, with_precedence: bool) -> Result<u64, AocError> {
+    lines(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .try_fold(0u64, |sum, line| {
+            let value = evaluate(line.text, with_precedence).map_err(|e| e.on_line(line.number))?;
+            sum.checked_add(value).ok_or_else(|| line.error(line.text, "the sum overflows"))
+        })
+}
+
+// Errors are reported on line 1; evaluate_expressions moves them to the right line
+pub fn evaluate(expression: &str, with_precedence: bool) -> Result<u64, AocError> {
+
+    let error = |column: usize, message: &str| AocError::at(1, column, expression, message);
+
+    // Err is the problem with the expression
+    fn apply_operator(value_stack: &mut Vec<u64>, operator: char) -> Result<(), &'static str> {
+        let v1 = value_stack.pop().ok_or("missing operand")?;
+        let v2 = value_stack.pop().ok_or("missing operand")?;
+        let value = match operator {
+            '+' => v1.checked_add(v2),
+            '*' => v1.checked_mul(v2),
+            _ => return Err("missing operand")
+        };
+        value_stack.push(value.ok_or("overflow")?);
+        Ok(())
+    }
+
+    let mut value_stack: Vec<u64> = Vec::new();
+    let mut operator_stack: Vec<char> = Vec::new();
+
+    for (i, c) in expression.chars().enumerate() {
+        let column = i + 1;
+        match c {
+            '0'..='9' => value_stack.push(c.to_digit(10).unwrap() as u64),
+            '(' => operator_stack.push('('),
+            ')' => {
+                loop {
+                    match operator_stack.pop() {
+                        Some('(') => break,
+                        Some(o) => apply_operator(&mut value_stack, o).map_err(|e| error(column, e))?,
+                        None => return Err(error(column, "unmatched ')'"))
+                    }
+                }
+            },
+            '+' | '*' => {
+                while let Some(o) = operator_stack.last() {
+                    if *o != '+' && *o != '*' {
+                        break;
+                    }
+                    if with_precedence && c == '+' && *o == '*' {
+                        break;
+                    }
+                    let o = operator_stack.pop().unwrap();
+                    apply_operator(&mut value_stack, o).map_err(|e| error(column, e))?;
+                }
+                operator_stack.push(c);
+            },
+            ' ' => (),
+            _ => return Err(error(column, &format!("unexpected {:?}", c)))
+        }
+    }
+
+    let end = expression.chars().count() + 1;
+    while let Some(o) = operator_stack.pop() {
+        if o == '(' {
+            return Err(error(end, "expected ')'"));
+        }
+        apply_operator(&mut value_stack, o).map_err(|e| error(end, e))?;
+    };
+
+    match (value_stack.pop(), value_stack.is_empty()) {
+        (Some(value), true) => Ok(value),
+        (None, _) => Err(error(end, "expected an expression")),
+        (Some(_), false) => Err(error(end, "missing operator"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day18::*;
+    use proptest::prelude::*;
+
+    #[derive(Debug, Clone)]
+    enum Expr {
+        Number(u64),
+        Add(Box<Expr>, Box<Expr>),
+        Multiply(Box<Expr>, Box<Expr>)
+    }
+
+    impl Expr {
+        fn value(&self) -> Option<u64> {
+            match self {
+                Expr::Number(n) => Some(*n),
+                Expr::Add(a, b) => a.value()?.checked_add(b.value()?),
+                Expr::Multiply(a, b) => a.value()?.checked_mul(b.value()?)
+            }
+        }
+
+        // Every operation is bracketed, so precedence makes no difference
+        fn print(&self) -> String {
+            match self {
+                Expr::Number(n) => n.to_string(),
+                Expr::Add(a, b) => format!("({} + {})", a.print(), b.print()),
+                Expr::Multiply(a, b) => format!("({} * {})", a.print(), b.print())
+            }
+        }
+    }
+
+    fn expr() -> impl Strategy<Value = Expr> {
+        (1..10u64).prop_map(Expr::Number).prop_recursive(6, 64, 2, |inner| prop_oneof![
+            (inner.clone(), inner.clone()).prop_map(|(a, b)| Expr::Add(Box::new(a), Box::new(b))),
+            (inner.clone(), inner).prop_map(|(a, b)| Expr::Multiply(Box::new(a), Box::new(b)))
+        ])
+    }
+
+    #[test]
+    fn test_evaluate_expressions() {
+        let expressions = vec![
+            ("1 + 2 * 3 + 4 * 5 + 6", 71),
+            ("1 + (2 * 3) + (4 * (5 + 6))", 51),
+            ("2 * 3 + (4 * 5)", 26),
+            ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437),
+            ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240),
+            ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 13632),
+        ];
+
+        for e in expressions {
+            let value = evaluate(e.0, false).unwrap();
+            assert_eq!(e.1, value);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_expressions_with_precedence() {
+        let expressions = vec![
+            ("1 + 2 * 3 + 4 * 5 + 6", 231),
+            ("1 + (2 * 3) + (4 * (5 + 6))", 51),
+            ("2 * 3 + (4 * 5)", 46),
+            ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 1445),
+            ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 669060),
+            ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 23340),
+        ];
+
+        for e in expressions {
+            let value = evaluate(e.0, true).unwrap();
+            assert_eq!(e.1, value);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_reports_location() {
+        let err = evaluate_expressions("1 + 2\n3 * x", false).unwrap_err();
+
+        assert_eq!(r#"line 2, column 5: unexpected 'x' in "3 * x""#, err.to_string());
+    }
+
+    #[test]
+    fn test_evaluate_reports_overflow() {
+        let expression = vec!["9"; 30].join(" * ");
+
+        assert_eq!("overflow", evaluate(&expression, false).unwrap_err().message);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_bracketed_expressions_evaluate_to_their_value(e in expr()) {
+            let printed = e.print();
+
+            prop_assert_eq!(e.value(), evaluate(&printed, false).ok());
+            prop_assert_eq!(e.value(), evaluate(&printed, true).ok());
+        }
+
+        #[test]
+        fn prop_sums_ignore_precedence(numbers in proptest::collection::vec(1..10u64, 1..20)) {
+            let printed: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+            let expression = printed.join(" + ");
+
+            prop_assert_eq!(Ok(numbers.iter().sum::<u64>()), evaluate(&expression, false));
+            prop_assert_eq!(Ok(numbers.iter().sum::<u64>()), evaluate(&expression, true));
+        }
+
+        #[test]
+        fn prop_evaluate_never_panics(expression in "[0-9 +*()]{0,40}") {
+            let _ = evaluate(&expression, false);
+            let _ = evaluate(&expression, true);
+        }
+    }
+}
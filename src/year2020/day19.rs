@@ -0,0 +1,409 @@
+use crate::cancel;
+use crate::error::{lines, AocError, Line};
+use crate::input::{next_record, records};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use crate::year2020::day19::Rule::*;
+
+// Part 2 replaces these two rules with versions that loop
+const LOOPING_RULES: &str = "8: 42 | 42 8\n11: 42 31 | 42 11 31";
+
+pub struct Messages {
+    rule_set: RuleSet,
+    looping_rule_set: RuleSet,
+    messages: Vec<String>
+}
+
+impl Solution for Messages {
+    fn parse(input: &str) -> Result<Messages, AocError> {
+        let (rule_set, messages) = parse(input)?;
+        let (mut looping_rule_set, _) = parse(input)?;
+        looping_rule_set.rules.extend(RuleSet::parse(&lines(LOOPING_RULES).collect::<Vec<_>>())?.rules);
+
+        Ok(Messages {
+            rule_set,
+            looping_rule_set,
+            messages
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(count_matches(&self.rule_set, &self.messages)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        // Part 1's rules needn't have the 42 and 31 that the looping rules use
+        self.looping_rule_set.validate()?;
+        Ok(count_matches(&self.looping_rule_set, &self.messages)?.into())
+    }
+}
+
+fn parse(input: &str) -> Result<(RuleSet, Vec<String>), AocError> {
+
+    let mut sections = records(input).into_iter();
+ 
+    let rules = RuleSet::parse(&next_record(&mut sections, "the rules")?.lines)?;
+    rules.validate()?;
+
+    let messages: Vec<String> = next_record(&mut sections, "a blank line followed by the messages")?
+        .lines
+        .iter()
+        .map(|line| line.text.trim().to_string())
+        .collect();
+
+    Ok((
+        rules,
+        messages
+    ))
+}
+
+fn count_matches(rule_set: &RuleSet, messages: &[String]) -> Result<usize, AocError> {
+    let mut count = 0;
+    for message in messages {
+        if rule_set.is_match(message)? {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+#[derive(Debug)]   
+enum Rule {
+    Letter(char),
+    Seq(Vec<usize>),
+    Or(Box<Rule>, Box<Rule>)
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Letter(letter) => write!(f, "\"{}\"", letter),
+            Seq(seq) => {
+                let seq: Vec<String> = seq.iter().map(|r| r.to_string()).collect();
+                write!(f, "{}", seq.join(" "))
+            },
+            Or(a, b) => write!(f, "{} | {}", a, b)
+        }
+    }
+}
+
+#[derive(Debug)]   
+struct RuleSet {
+    rules: HashMap<usize, Rule>
+}
+
+// One rule per line in the puzzle's format, in rule number order
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut numbers: Vec<&usize> = self.rules.keys().collect();
+        numbers.sort();
+        for number in numbers {
+            writeln!(f, "{}: {}", number, self.rules[number])?;
+        }
+        Ok(())
+    }
+}
+
+impl RuleSet {
+    fn parse(lines: &[Line]) -> Result<RuleSet, AocError> {
+        
+        fn parse_line(line: &Line) -> Result<(usize, Rule), AocError> {
+            let mut parts = line.text.trim().split(':');
+            Ok((
+                line.parse(parts.next().unwrap(), "a rule number")?,
+                parse_rule(line, line.next(&mut parts, "':' followed by a rule")?.trim())?
+            ))
+        }
+    
+        fn parse_rule(line: &Line, text: &str) -> Result<Rule, AocError> {
+            if text.contains('"') {
+                let letter = text.replace('"', "");
+                let mut chars = letter.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(letter), None) => Ok(Rule::Letter(letter)),
+                    _ => Err(line.error(text, "expected a single quoted letter"))
+                }
+            } else if text.contains('|') {
+                let mut parts = text.split('|');
+    
+                Ok(Rule::Or(
+                    Box::new(Seq(parse_seq(line, parts.next().unwrap())?)),
+                    Box::new(Seq(parse_seq(line, parts.next().unwrap())?))
+                ))
+            } else {
+                Ok(Rule::Seq(parse_seq(line, text)?))
+            }
+        }
+    
+        fn parse_seq(line: &Line, text: &str) -> Result<Vec<usize>, AocError> {
+            let seq: Vec<usize> = text
+                .split_whitespace()
+                .map(|s| line.parse(s, "a rule number"))
+                .collect::<Result<_, _>>()?;
+            if seq.is_empty() {
+                return Err(line.error(text, "expected a sequence of rules"));
+            }
+            Ok(seq)
+        }
+    
+        Ok(RuleSet {
+            rules: lines
+                .iter()
+                .map(parse_line)
+                .collect::<Result<_, _>>()?
+        })
+    }
+
+    // Check rule 0 exists and every rule refers only to rules that exist, so matching
+    // can index the rules without checking
+    fn validate(&self) -> Result<(), AocError> {
+
+        fn references(rule: &Rule) -> Vec<usize> {
+            match rule {
+                Letter(_) => Vec::new(),
+                Seq(v) => v.clone(),
+                Or(r1, r2) => references(r1).into_iter().chain(references(r2)).collect()
+            }
+        }
+
+        if !self.rules.contains_key(&0) {
+            return Err(AocError::new("expected a rule 0"));
+        }
+
+        // In order, so that the same rule is always the one reported
+        let mut numbers: Vec<_> = self.rules.keys().collect();
+        numbers.sort();
+        for number in numbers {
+            let rule = &self.rules[number];
+            if let Some(missing) = references(rule).iter().find(|r| !self.rules.contains_key(r)) {
+                return Err(AocError::new(format!("rule {} refers to missing rule {}", number, missing)));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_match(&self, message: &str) -> Result<bool, AocError> {
+        let mut progress = Progress { matching: Vec::new(), steps: 0 };
+        Ok(self
+            .solve_rule(0, message, &mut progress)?
+            .iter()
+            .any(|remainder| remainder.is_empty()))
+    }
+
+    // A rule that comes back round to itself without matching anything in between
+    // would go on for ever, so it is an error
+    fn solve_rule<'a>(&self, number: usize, message: &'a str, progress: &mut Progress) -> Result<Vec<&'a str>, AocError> {
+        if progress.matching.contains(&(number, message.len())) {
+            return Err(AocError::new(format!("rule {} refers back to itself without matching anything", number)));
+        }
+
+        progress.matching.push((number, message.len()));
+        let remainders = self.solve(&self.rules[&number], message, progress);
+        progress.matching.pop();
+        remainders
+    }
+
+    // Returns every remainder of the message left over after matching the rule against
+    // its start. Trying both sides of each Or allows the looping rules to backtrack.
+    fn solve<'a>(&self, rule: &Rule, message: &'a str, progress: &mut Progress) -> Result<Vec<&'a str>, AocError> {
+        progress.steps += 1;
+        cancel::check_every(progress.steps)?;
+
+        Ok(match rule {
+            Letter(l) => {
+                if message.starts_with(*l) {
+                    vec![&message[l.len_utf8()..]]
+                } else {
+                    Vec::new()
+                }
+            },
+            Seq(v) => {
+                let mut remainders = vec![message];
+                for i in v {
+                    let mut next = Vec::new();
+                    for m in remainders {
+                        next.extend(self.solve_rule(*i, m, progress)?);
+                    }
+                    remainders = next;
+                }
+                remainders
+            },
+            Or(r1, r2) => {
+                let mut remainders = self.solve(r1, message, progress)?;
+                remainders.extend(self.solve(r2, message, progress)?);
+                remainders
+            }
+        })
+    }    
+}
+
+// The rules being matched, each with the length of the message left when it started,
+// and how many steps matching has taken so far
+struct Progress {
+    matching: Vec<(usize, usize)>,
+    steps: usize
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day19::*;
+    use proptest::prelude::*;
+
+    fn rule_text() -> impl Strategy<Value = String> {
+        let seq = || proptest::collection::vec(0..20usize, 1..4)
+            .prop_map(|seq| seq.iter().map(|r| r.to_string()).collect::<Vec<_>>().join(" "));
+        prop_oneof![
+            "[ab]".prop_map(|letter| format!("\"{}\"", letter)),
+            seq(),
+            (seq(), seq()).prop_map(|(a, b)| format!("{} | {}", a, b))
+        ]
+    }
+    use crate::year2020::examples;
+
+    #[test]
+    fn test_rules_match() {
+
+        let input = r#"
+0: 1 2
+1: "a"
+2: 1 3 | 3 1
+3: "b"
+
+aba
+                "#;
+
+        let (rule_set, messages) = parse(input).unwrap();
+        let result = rule_set.is_match(&messages[0]).unwrap();
+
+        assert!(result);
+    }
+
+    #[test]
+    fn test_rules_2() {
+        let input = examples::input(19, "example");        
+
+        let (rule_set, messages) = parse(input).unwrap();
+        let result = count_matches(&rule_set, &messages).unwrap();
+        assert_eq!(3, result);
+    }
+
+
+    #[test]
+    fn test_rules_3() {
+        let input = r#"
+42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: "a"
+11: 42 31 | 42 11 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: "b"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42 | 42 8
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
+                "#;        
+
+        let (rule_set, messages) = parse(input).unwrap();
+        let result = count_matches(&rule_set, &messages).unwrap();
+        assert_eq!(12, result);
+    }
+
+    // Rule 8 matching a single 42 leaves too much for rule 31, so the matcher has to
+    // go back and try the other side of 8
+    #[test]
+    fn test_looping_rules_backtrack() {
+        let input = "0: 8 31\n8: 42 | 42 8\n42: \"a\"\n31: \"b\"\n\naaab\nab\naa\nb";
+
+        let (rule_set, messages) = parse(input).unwrap();
+
+        assert_eq!(vec![true, true, false, false], messages.iter().map(|m| rule_set.is_match(m).unwrap()).collect::<Vec<_>>());
+    }
+
+    // The puzzle's copy of the input with rules 8 and 11 already changed matches the
+    // same messages as part 2, which changes them itself
+    #[test]
+    fn test_part2_input() {
+        let (rule_set, messages) = parse(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/input/2020/day19_part2.txt"))).unwrap();
+        let expected = Messages::parse(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/input/2020/day19.txt"))).unwrap().part2();
+
+        assert_eq!(Ok(Answer::Number(414)), expected);
+        assert_eq!(Ok(414), count_matches(&rule_set, &messages));
+    }
+
+    #[test]
+    fn test_missing_rule() {
+        let input = "0: 1 2\n1: \"a\"\n\na";
+
+        let err = parse(input).unwrap_err();
+
+        assert_eq!("rule 0 refers to missing rule 2", err.to_string());
+    }
+
+    #[test]
+    fn test_rules_that_loop_without_matching() {
+        let messages = Messages::parse("0: 0 1 | 1\n1: \"a\"\n\naa\n").unwrap();
+
+        let err = messages.part1().unwrap_err();
+        assert_eq!("rule 0 refers back to itself without matching anything", err.to_string());
+
+        // Part 2's rules 8 and 11 need rules 42 and 31
+        let messages = Messages::parse("0: 1\n1: \"a\"\n\na\n").unwrap();
+        assert_eq!(Ok(1.into()), messages.part1());
+        let err = messages.part2().unwrap_err();
+        assert_eq!("rule 8 refers to missing rule 42", err.to_string());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_parsed_rules_print_back(rules in proptest::collection::vec(rule_text(), 1..20)) {
+            let text: String = rules
+                .iter()
+                .enumerate()
+                .map(|(number, rule)| format!("{}: {}\n", number, rule))
+                .collect();
+
+            let rule_set = RuleSet::parse(&lines(&text).collect::<Vec<_>>()).unwrap();
+
+            prop_assert_eq!(text, rule_set.to_string());
+        }
+    }
+}
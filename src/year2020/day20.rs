@@ -0,0 +1,674 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+use crate::year2020::day20::assembly::Arrangement;
+use crate::year2020::day20::part1::*;
+use std::fmt::Display;
+
+pub struct Jigsaw {
+    arrangements: Vec<Arrangement>
+}
+
+impl Solution for Jigsaw {
+    fn parse(input: &str) -> Result<Jigsaw, AocError> {
+        // Build a hashmap of id -> tile
+        // For each tile also enumerate each edge (and in flipped orientation) as a number
+        let tiles = parse(input)?;
+
+        // Build a map of id -> neighbours (a neighbour consists of id and the connecting edge number)
+        // For the real puzzle each edge only matches once, which makes it easy: we expect
+        // internal tiles to have 4 neighbours
+        // edge tiles to have 3 neighbours and 
+        // corner tiles to have 2 neighbours
+        // and the tiles can be laid out one after another. Where edges match more than
+        // once the tiles are arranged by searching every way they can go instead.
+        let neighbours = find_all_neighbours(&tiles);
+
+        Ok(Jigsaw {
+            arrangements: assembly::arrange(&tiles, &neighbours)?
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let answers = self.arrangements.iter().map(|a| Ok(a.corners().iter().product::<usize>()));
+        Ok(agreed_answer(&self.arrangements, answers)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let answers = self.arrangements.iter().map(|a| part2::roughness(&a.image()));
+        Ok(agreed_answer(&self.arrangements, answers)?.into())
+    }
+
+    // The stitched image with any sea monsters picked out. If the tiles fit together
+    // more than one way, each way's image is drawn below the last with a blank row
+    // between them.
+    fn picture(&self, _part: u32) -> Result<Option<Grid<char>>, AocError> {
+        let images: Vec<Grid<char>> = self
+            .arrangements
+            .iter()
+            .map(|arrangement| {
+                let image = arrangement.image();
+                part2::find_sea_monsters(&image).unwrap_or_else(|| image.map(|pixel| if *pixel { '#' } else { '.' }))
+            })
+            .collect();
+
+        let first = images
+            .first()
+            .ok_or_else(|| AocError::new("there is no arrangement of the tiles where they all fit together"))?;
+        let (width, height) = (first.width(), first.height());
+
+        Ok(Some(Grid::from_fn(width, images.len() * (height + 1) - 1, |x, y| {
+            match y % (height + 1) {
+                row if row == height => ' ',
+                row => images[y / (height + 1)][(x, row)]
+            }
+        })))
+    }
+}
+
+// The tile ids of each arrangement, a blank line between each
+fn layouts(arrangements: &[Arrangement]) -> String {
+    let layouts: Vec<String> = arrangements.iter().map(|arrangement| arrangement.to_string()).collect();
+    layouts.join("\n")
+}
+
+// The answer every arrangement of the tiles gives. When they don't all give the same
+// one, the error lists each arrangement with its answer; when they do but there is
+// more than one, the arrangements are logged as a warning.
+fn agreed_answer<T, I>(arrangements: &[Arrangement], answers: I) -> Result<T, AocError>
+where
+    T: PartialEq + Display,
+    I: Iterator<Item = Result<T, AocError>> {
+
+    let answers: Vec<T> = answers.collect::<Result<_, _>>()?;
+
+    match answers.first() {
+        None => Err(AocError::new("there is no arrangement of the tiles where they all fit together")),
+        Some(first) if answers.iter().all(|answer| answer == first) => {
+            if answers.len() > 1 {
+                log::warn!(
+                    "the tiles fit together {} ways, all giving {}:\n{}",
+                    arrangements.len(),
+                    first,
+                    layouts(arrangements));
+            }
+            Ok(answers.into_iter().next().unwrap())
+        },
+        Some(_) => {
+            let ways: Vec<String> = arrangements
+                .iter()
+                .zip(answers.iter())
+                .map(|(arrangement, answer)| format!("{}giving {}", arrangement, answer))
+                .collect();
+            Err(AocError::new(format!(
+                "the tiles fit together {} ways with different answers:\n{}",
+                arrangements.len(),
+                ways.join("\n"))))
+        }
+    }
+}
+
+mod part1 {
+    use crate::error::AocError;
+    use crate::input::{records, Record};
+    use crate::grid::Grid;
+    use std::collections::HashMap;
+
+    pub const TILE_SIZE: usize = 10;
+
+    pub fn parse(input: &str) -> Result<HashMap<usize, Tile>, AocError> {
+        records(input)
+            .iter()
+            .map(|record| {
+                let tile = Tile::parse(record)?;
+                Ok((
+                    tile.id,
+                    tile
+                ))
+            })
+            .collect()
+    }
+
+    pub fn find_all_neighbours(tiles: &HashMap<usize, Tile>) -> HashMap<usize, Vec<Neighbour>> {
+        tiles
+            .values()
+            .map(|tile| {
+                (
+                    tile.id,
+                    tiles
+                        .iter()
+                        .filter(|(id, _)| **id != tile.id) // exclude self
+                        .filter_map(|(_, other)| tile.find_neighbour(other))
+                        .collect()
+                )
+            })
+            .collect()
+    }
+
+    pub fn find_corners(neighbours: &HashMap<usize, Vec<Neighbour>>) -> Vec<usize> {
+        neighbours
+            .iter()
+            //.inspect(|p| println!("{:?}", p))
+            .filter(|(_, neighbours)| neighbours.len() == 2)
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    #[derive(Debug)] 
+    pub struct Neighbour {
+        pub tile_id: usize,
+        #[allow(dead_code)]
+        edge: u32, // 0-7 4 edges * 2 flips
+    }
+
+    impl Neighbour {
+        fn new(tile_id: usize, edge: u32) -> Neighbour {
+            Neighbour {
+                tile_id,
+                edge
+            }
+        }
+    }
+
+    // Where each edge is in the list from edges. Top and bottom read left to right,
+    // left and right top to bottom, so the edges of two tiles that fit side by side
+    // are equal.
+    pub const TOP: usize = 0;
+    pub const RIGHT: usize = 1;
+    pub const BOTTOM: usize = 2;
+    pub const LEFT: usize = 3;
+
+    // [top, right, bottom, left, flip top, flip right, flip bottom, flip left]
+    pub fn edges(image: &Grid<bool>) -> Vec<u32> {
+
+        fn to_int<'a>(edge: impl Iterator<Item = &'a bool>) -> u32 {
+            edge.fold(0, |v, b| {
+                let mut val = v;
+                if *b { val |= 1; }
+                val << 1
+            })
+        }
+
+        let top = image.row(0);
+        let bottom = image.row(image.height() - 1);
+        let left = || image.column(0);
+        let right = || image.column(image.width() - 1);
+
+        vec![
+            to_int(top.iter()), // top
+            to_int(right()), // right
+            to_int(bottom.iter()), // bottom
+            to_int(left()), // left
+            to_int(top.iter().rev()), // flip top
+            to_int(right().rev()), // flip right
+            to_int(bottom.iter().rev()), // flip bottom
+            to_int(left().rev()), // flip left
+        ]
+    }
+
+    #[derive(Debug)] 
+    pub struct Tile {
+        pub id: usize,
+        pub image: Grid<bool>,
+        pub edges: Vec<u32>, // see edges
+    }
+
+    impl Tile {
+        fn parse(record: &Record) -> Result<Tile, AocError> {
+            let header = record.lines[0];
+            let id: usize = match header.text.trim().strip_prefix("Tile ").and_then(|s| s.strip_suffix(':')) {
+                Some(id) => header.parse(id, "a tile id")?,
+                None => return Err(header.error(header.text, "expected \"Tile <id>:\""))
+            };
+
+            if record.body().is_empty() {
+                return Err(header.missing(format!("{}x{} pixels on the next lines", TILE_SIZE, TILE_SIZE)));
+            }
+
+            let image = Grid::parse_lines(record.body().iter().copied(), |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None
+            })?;
+
+            if image.width() != TILE_SIZE || image.height() != TILE_SIZE {
+                return Err(header.error(header.text, format!("expected {}x{} pixels", TILE_SIZE, TILE_SIZE)));
+            }
+
+            Ok(Tile {
+                id,
+                edges: edges(&image),
+                image
+            })
+        }
+
+        fn find_neighbour(&self, other: &Tile) -> Option<Neighbour> {
+            self
+                .edges
+                .iter()
+                .find_map(|e| other
+                    .edges
+                    .iter()
+                    .find(|o| *o == e)
+                    .map(|o| Neighbour::new(other.id, *o))
+                )
+        }
+    }
+}
+
+mod assembly {
+    use crate::cancel;
+    use crate::error::AocError;
+    use crate::grid::Grid;
+    use crate::year2020::day20::part1::*;
+    use std::collections::{HashMap, HashSet};
+    use std::fmt;
+
+    // The tiles laid out in a square, row by row, each turned the way it goes
+    pub struct Arrangement {
+        side: usize,
+        placed: Vec<(usize, Grid<bool>)>
+    }
+
+    impl Arrangement {
+        pub fn corners(&self) -> Vec<usize> {
+            let last = self.side - 1;
+            [(0, 0), (last, 0), (0, last), (last, last)]
+                .iter()
+                .map(|(x, y)| self.placed[y * self.side + x].0)
+                .collect()
+        }
+
+        // The picture with the tile borders stripped off
+        pub fn image(&self) -> Grid<bool> {
+            self.stitch(1)
+        }
+
+        fn stitch(&self, border: usize) -> Grid<bool> {
+            let inner = TILE_SIZE - 2 * border;
+            Grid::from_fn(self.side * inner, self.side * inner, |x, y| {
+                self.placed[(y / inner) * self.side + x / inner].1[(x % inner + border, y % inner + border)]
+            })
+        }
+
+        fn ids(&self) -> Grid<usize> {
+            Grid::from_fn(self.side, self.side, |x, y| self.placed[y * self.side + x].0)
+        }
+
+        // Turning or flipping a whole arrangement gives another seven that fit. Only the
+        // one that comes first by tile ids, then pixels, is kept.
+        fn is_canonical(&self) -> bool {
+            let ids = self.ids().orientations();
+            let pictures = self.stitch(0).orientations();
+            let key = |i: usize| (ids[i].iter().collect::<Vec<_>>(), pictures[i].iter().collect::<Vec<_>>());
+            let first = key(0);
+            (1..8).all(|i| first <= key(i))
+        }
+    }
+
+    // One row of tile ids per row of the arrangement
+    impl fmt::Display for Arrangement {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            for row in self.placed.chunks(self.side) {
+                let ids: Vec<String> = row.iter().map(|(id, _)| id.to_string()).collect();
+                writeln!(f, "{}", ids.join(" "))?;
+            }
+            Ok(())
+        }
+    }
+
+    fn square_side(tiles: usize) -> Result<usize, AocError> {
+        match (1..=tiles).find(|n| n * n >= tiles) {
+            Some(side) if side * side == tiles => Ok(side),
+            _ => Err(AocError::new(format!("{} tiles don't make a square", tiles)))
+        }
+    }
+
+    // Every way the tiles fit together, up to turning the whole picture over. When each
+    // edge matches only one other tile there is just the one way, found directly;
+    // otherwise it takes a search.
+    pub fn arrange(tiles: &HashMap<usize, Tile>, neighbours: &HashMap<usize, Vec<Neighbour>>) -> Result<Vec<Arrangement>, AocError> {
+        square_side(tiles.len())?;
+
+        if edges_match_once(tiles) && find_corners(neighbours).len() == 4 {
+            if let Ok(arrangement) = assemble(tiles, neighbours) {
+                return Ok(vec![arrangement]);
+            }
+        }
+
+        search(tiles)
+    }
+
+    fn edges_match_once(tiles: &HashMap<usize, Tile>) -> bool {
+        let mut matches: HashMap<u32, usize> = HashMap::new();
+        for tile in tiles.values() {
+            let edges: HashSet<u32> = tile.edges.iter().copied().collect();
+            for edge in edges {
+                *matches.entry(edge).or_insert(0) += 1;
+            }
+        }
+        matches.values().all(|n| *n <= 2)
+    }
+
+    // Lay the tiles out in a square, row by row. The first is a corner, turned so that
+    // the edges it shares face right and down; every other tile is the neighbour of the
+    // one to its left (or, starting a row, the one above) turned to fit against it.
+    pub fn assemble(tiles: &HashMap<usize, Tile>, neighbours: &HashMap<usize, Vec<Neighbour>>) -> Result<Arrangement, AocError> {
+        let side = square_side(tiles.len())?;
+
+        let corner = find_corners(neighbours)
+            .into_iter()
+            .min()
+            .ok_or_else(|| AocError::new("there are no corner tiles"))?;
+
+        let shared: HashSet<u32> = neighbours[&corner]
+            .iter()
+            .flat_map(|neighbour| tiles[&neighbour.tile_id].edges.iter().copied())
+            .collect();
+
+        let first = tiles[&corner]
+            .image
+            .orientations()
+            .into_iter()
+            .find(|image| {
+                let edges = edges(image);
+                shared.contains(&edges[RIGHT]) && shared.contains(&edges[BOTTOM])
+            })
+            .ok_or_else(|| AocError::new(format!("tile {} can't be turned to start the image", corner)))?;
+
+        let mut placed: Vec<(usize, Grid<bool>)> = vec![(corner, first)];
+        let mut used: HashSet<usize> = [corner].iter().copied().collect();
+
+        for i in 1..tiles.len() {
+            let (x, y) = (i % side, i / side);
+            let (next_to, edge, facing) = if x > 0 {
+                (&placed[i - 1], RIGHT, LEFT)
+            } else {
+                (&placed[i - side], BOTTOM, TOP)
+            };
+            let edge_value = edges(&next_to.1)[edge];
+
+            let (id, image) = neighbours[&next_to.0]
+                .iter()
+                .filter(|neighbour| !used.contains(&neighbour.tile_id))
+                .find_map(|neighbour| {
+                    tiles[&neighbour.tile_id]
+                        .image
+                        .orientations()
+                        .into_iter()
+                        .find(|image| edges(image)[facing] == edge_value)
+                        .map(|image| (neighbour.tile_id, image))
+                })
+                .ok_or_else(|| AocError::new(format!("no tile fits against tile {} at ({}, {})", next_to.0, x, y)))?;
+
+            if x > 0 && y > 0 && edges(&placed[i - side].1)[BOTTOM] != edges(&image)[TOP] {
+                return Err(AocError::new(format!("tile {} doesn't fit under tile {}", id, placed[i - side].0)));
+            }
+
+            used.insert(id);
+            placed.push((id, image));
+        }
+
+        Ok(Arrangement { side, placed })
+    }
+
+    // Depth first search over which tile goes where and which way round, filling the
+    // square row by row. A tile is only tried where its edges match those already
+    // placed beside it, and only kept if something can still go in the next place.
+    pub fn search(tiles: &HashMap<usize, Tile>) -> Result<Vec<Arrangement>, AocError> {
+        let mut ids: Vec<usize> = tiles.keys().copied().collect();
+        ids.sort_unstable();
+
+        // The different ways each tile can be turned, as a symmetric tile looks the same
+        // more than one way
+        let turns: Vec<Vec<(Grid<bool>, Vec<u32>)>> = ids
+            .iter()
+            .map(|id| {
+                let mut turns: Vec<Grid<bool>> = Vec::new();
+                for image in tiles[id].image.orientations() {
+                    if !turns.contains(&image) {
+                        turns.push(image);
+                    }
+                }
+                turns.into_iter().map(|image| { let edges = edges(&image); (image, edges) }).collect()
+            })
+            .collect();
+
+        let mut by_left: HashMap<u32, Vec<(usize, usize)>> = HashMap::new();
+        let mut by_top: HashMap<u32, Vec<(usize, usize)>> = HashMap::new();
+        for (tile, turns) in turns.iter().enumerate() {
+            for (turn, (_, edges)) in turns.iter().enumerate() {
+                by_left.entry(edges[LEFT]).or_default().push((tile, turn));
+                by_top.entry(edges[TOP]).or_default().push((tile, turn));
+            }
+        }
+
+        let mut search = Search {
+            side: square_side(tiles.len())?,
+            turns: &turns,
+            by_left,
+            by_top,
+            used: vec![false; ids.len()],
+            placed: Vec::with_capacity(ids.len()),
+            found: Vec::new(),
+            steps: 0
+        };
+        search.extend()?;
+
+        Ok(search
+            .found
+            .iter()
+            .map(|placed| Arrangement {
+                side: search.side,
+                placed: placed.iter().map(|(tile, turn)| (ids[*tile], turns[*tile][*turn].0.clone())).collect()
+            })
+            .filter(Arrangement::is_canonical)
+            .collect())
+    }
+
+    // Tiles and turns are indexes into the list of each tile's turns
+    struct Search<'a> {
+        side: usize,
+        turns: &'a [Vec<(Grid<bool>, Vec<u32>)>],
+        by_left: HashMap<u32, Vec<(usize, usize)>>,
+        by_top: HashMap<u32, Vec<(usize, usize)>>,
+        used: Vec<bool>,
+        placed: Vec<(usize, usize)>,
+        found: Vec<Vec<(usize, usize)>>,
+        steps: usize
+    }
+
+    impl Search<'_> {
+        fn edge(&self, i: usize, edge: usize) -> u32 {
+            let (tile, turn) = self.placed[i];
+            self.turns[tile][turn].1[edge]
+        }
+
+        // What could go in the next place, given those already placed
+        fn candidates(&self) -> Vec<(usize, usize)> {
+            let i = self.placed.len();
+            let (x, y) = (i % self.side, i / self.side);
+            let unused = |(tile, _): &(usize, usize)| !self.used[*tile];
+
+            if i == 0 {
+                return (0..self.turns.len())
+                    .flat_map(|tile| (0..self.turns[tile].len()).map(move |turn| (tile, turn)))
+                    .collect();
+            }
+
+            if x == 0 {
+                let above = self.edge(i - self.side, BOTTOM);
+                return self.by_top.get(&above).into_iter().flatten().copied().filter(unused).collect();
+            }
+
+            let left = self.edge(i - 1, RIGHT);
+            let above = if y > 0 { Some(self.edge(i - self.side, BOTTOM)) } else { None };
+            self.by_left
+                .get(&left)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(unused)
+                .filter(|(tile, turn)| above.is_none_or(|above| self.turns[*tile][*turn].1[TOP] == above))
+                .collect()
+        }
+
+        fn extend(&mut self) -> Result<(), AocError> {
+            if self.placed.len() == self.used.len() {
+                self.found.push(self.placed.clone());
+                return Ok(());
+            }
+
+            for (tile, turn) in self.candidates() {
+                self.steps += 1;
+                cancel::check_every(self.steps)?;
+
+                self.used[tile] = true;
+                self.placed.push((tile, turn));
+
+                if self.placed.len() == self.used.len() || !self.candidates().is_empty() {
+                    self.extend()?;
+                }
+
+                self.placed.pop();
+                self.used[tile] = false;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+mod part2 {
+    use crate::error::AocError;
+    use crate::grid::Grid;
+
+    const MONSTER: [&str; 3] = [
+        "                  # ",
+        "#    ##    ##    ###",
+        " #  #  #  #  #  #   "
+    ];
+
+    // The image turned the way that shows the most sea monsters, drawn as in the puzzle
+    // description with the monsters picked out in 'O'. None if there are no monsters
+    // whichever way it is turned.
+    pub fn find_sea_monsters(image: &Grid<bool>) -> Option<Grid<char>> {
+        let monster: Vec<(usize, usize)> = MONSTER
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.chars().enumerate().filter(|(_, c)| *c == '#').map(move |(x, _)| (x, y)))
+            .collect();
+        let (width, height) = (MONSTER[0].len(), MONSTER.len());
+
+        image
+            .orientations()
+            .iter()
+            .map(|image| {
+                let mut drawn = image.map(|pixel| if *pixel { '#' } else { '.' });
+                let mut found = 0;
+
+                for (x, y) in image.positions().filter(|(x, y)| x + width <= image.width() && y + height <= image.height()) {
+                    if monster.iter().all(|(dx, dy)| image[(x + dx, y + dy)]) {
+                        found += 1;
+                        for (dx, dy) in &monster {
+                            drawn[(x + dx, y + dy)] = 'O';
+                        }
+                    }
+                }
+
+                (found, drawn)
+            })
+            .max_by_key(|(found, _)| *found)
+            .filter(|(found, _)| *found > 0)
+            .map(|(_, drawn)| drawn)
+    }
+
+    // The number of '#' that aren't part of a sea monster
+    pub fn roughness(image: &Grid<bool>) -> Result<usize, AocError> {
+        find_sea_monsters(image)
+            .map(|drawn| drawn.iter().filter(|c| **c == '#').count())
+            .ok_or_else(|| AocError::new("there are no sea monsters in the image"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::day20::*;
+    use crate::year2020::examples;
+
+    fn blank_tiles(ids: &[usize]) -> String {
+        ids.iter().map(|id| format!("Tile {}:\n{}\n", id, "..........\n".repeat(10))).collect()
+    }
+
+    #[test]
+    fn test_assemble_and_find_sea_monsters() {
+        let tiles = parse(examples::input(20, "test")).unwrap();
+        let neighbours = find_all_neighbours(&tiles);
+
+        let image = assembly::assemble(&tiles, &neighbours).unwrap().image();
+
+        assert_eq!((24, 24), (image.width(), image.height()));
+        assert_eq!(303, image.iter().filter(|pixel| **pixel).count());
+        assert_eq!(273, part2::roughness(&image).unwrap());
+
+        let drawn = part2::find_sea_monsters(&image).unwrap();
+        assert_eq!(2 * 15, drawn.iter().filter(|c| **c == 'O').count());
+        assert_eq!(".####...#####..#...###..\n", &drawn.to_string()[..25]);
+    }
+
+    #[test]
+    fn test_search_finds_the_same_arrangement() {
+        let tiles = parse(examples::input(20, "test")).unwrap();
+
+        let arrangements = assembly::search(&tiles).unwrap();
+
+        assert_eq!(1, arrangements.len());
+        assert_eq!(273, part2::roughness(&arrangements[0].image()).unwrap());
+        let mut corners = arrangements[0].corners();
+        corners.sort_unstable();
+        assert_eq!(vec![1171, 1951, 2971, 3079], corners);
+    }
+
+    #[test]
+    fn test_search_finds_every_arrangement() {
+        // Blank tiles fit anywhere: 24 orders of 4 tiles, in groups of 8 that are the
+        // same picture turned over
+        let tiles = parse(&blank_tiles(&[1, 2, 3, 4])).unwrap();
+
+        let arrangements = assembly::search(&tiles).unwrap();
+
+        assert_eq!(3, arrangements.len());
+        assert_eq!("1 2\n3 4\n", arrangements[0].to_string());
+        assert_eq!(Ok(Answer::Number(24)), Jigsaw::parse(&blank_tiles(&[1, 2, 3, 4])).unwrap().part1());
+    }
+
+    #[test]
+    fn test_every_arrangement_is_shown() {
+        let jigsaw = Jigsaw::parse(&blank_tiles(&[1, 2, 3, 4])).unwrap();
+
+        assert_eq!("1 2\n3 4\n\n1 2\n4 3\n\n1 3\n4 2\n", layouts(&jigsaw.arrangements));
+
+        let picture = jigsaw.picture(1).unwrap().unwrap();
+        assert_eq!((16, 3 * 16 + 2), (picture.width(), picture.height()));
+        assert_eq!(vec![' '; 16], picture.row(16));
+        assert_eq!(vec!['.'; 16], picture.row(17));
+
+        // Answers that differ come with the arrangement giving each
+        let answers = vec![Ok(1), Ok(2), Ok(1)].into_iter();
+        let err = agreed_answer(&jigsaw.arrangements, answers).unwrap_err();
+        assert!(err.to_string().starts_with("the tiles fit together 3 ways with different answers:\n1 2\n3 4\ngiving 1\n"));
+    }
+
+    #[test]
+    fn test_no_arrangement() {
+        // With the top edge of the tile in the middle changed, nothing fits above it
+        let input = examples::input(20, "test").replacen("..##.#..#.", "#.#######.", 1);
+        let tiles = parse(&input).unwrap();
+
+        assert!(assembly::search(&tiles).unwrap().is_empty());
+
+        let err = Jigsaw::parse(&input).unwrap().part2().unwrap_err();
+        assert_eq!("there is no arrangement of the tiles where they all fit together", err.to_string());
+        assert!(Jigsaw::parse(&blank_tiles(&[1, 2, 3])).is_err());
+    }
+}
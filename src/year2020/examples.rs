@@ -0,0 +1,61 @@
+// The worked examples from the 2020 puzzle descriptions
+use crate::examples::{self, example, Example};
+use crate::year2020::YEAR;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(2020, 1, "example", Some("514579"), Some("241861950")),
+    example!(2020, 2, "example", Some("2"), Some("1")),
+    example!(2020, 4, "example", Some("2"), None),
+    example!(2020, 4, "valid", Some("4"), Some("4")),
+    example!(2020, 6, "example", Some("11"), Some("6")),
+    example!(2020, 7, "example", Some("4"), Some("32")),
+    example!(2020, 7, "deep", None, Some("126")),
+    example!(2020, 8, "example", Some("5"), Some("8")),
+    example!(2020, 10, "small", Some("35"), Some("8")),
+    example!(2020, 10, "large", Some("220"), Some("19208")),
+    example!(2020, 11, "seats", Some("37"), Some("26")),
+    example!(2020, 12, "example", Some("25"), Some("286")),
+    example!(2020, 13, "example", Some("295"), Some("1068781")),
+    example!(2020, 14, "floating", None, Some("208")),
+    example!(2020, 15, "example", Some("436"), Some("175594")),
+    example!(2020, 16, "example", Some("71"), None),
+    example!(2020, 17, "example", Some("112"), Some("848")),
+    example!(2020, 18, "example", Some("26457"), Some("694173")),
+    example!(2020, 19, "example", Some("3"), Some("12")),
+    example!(2020, 20, "test", Some("20899048083289"), Some("273")),
+];
+
+// The input of an example, for tests that exercise a day's internals on it
+pub fn input(day: u32, name: &str) -> &'static str {
+    examples::find(YEAR, day, name)
+        .unwrap_or_else(|| panic!("no example {} for day{}", name, day))
+        .input
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2020::examples::*;
+    use crate::year2020::registry;
+
+    // The days whose inputs are split into records at blank lines give the same answers
+    // for inputs edited on Windows or with blank lines left at the end
+    #[test]
+    fn test_records_tolerate_crlf_and_trailing_blank_lines() {
+        let days = registry();
+        let record_days = [4, 6, 16, 19, 20];
+
+        for example in EXAMPLES.iter().filter(|e| e.part1.is_some() && record_days.contains(&e.day)) {
+            let day = days.iter().find(|day| day.number == example.day).unwrap();
+            let input = example.input.replace('\n', "\r\n") + "\r\n  \r\n";
+
+            let answer = day.parse(&input).and_then(|solution| solution.part1());
+
+            assert_eq!(
+                example.part1.map(|answer| answer.to_string()),
+                answer.map(|answer| answer.to_string()).ok(),
+                "day{} example {}",
+                example.day,
+                example.name);
+        }
+    }
+}
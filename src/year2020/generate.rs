@@ -0,0 +1,869 @@
+// Input generators for the 2020 puzzles, see crate::generate
+use crate::generate::{at_least, at_most, lines, Generator, Rng};
+use crate::grid::{Grid, ALL_DIRECTIONS};
+use std::collections::HashSet;
+
+// Day, the size of the real input, or as near as the generator allows, and its
+// generator. What the size counts differs from day to day and is noted on each
+// generator, along with any limit on it.
+pub const GENERATORS: &[(u32, usize, Generator)] = &[
+    (1, 200, expense_report),
+    (2, 1000, password_database),
+    (3, 323, toboggan_map),
+    (4, 290, passport_batch),
+    (5, 789, boarding_passes),
+    (6, 490, customs_forms),
+    (7, 594, bag_rules),
+    (8, 633, boot_code),
+    (9, 400, xmas_data),
+    (10, 100, adaptors),
+    (11, 92, seat_layout),
+    (12, 777, navigation_instructions),
+    (13, 100, bus_notes),
+    (14, 577, docking_program),
+    (15, 6, starting_numbers),
+    (16, 240, ticket_notes),
+    (17, 8, conway_cubes),
+    (18, 370, homework),
+    (19, 400, messages),
+    (20, 12, jigsaw)
+];
+
+// size entries. The pair and the triple that sum to 2020 are all below 1010 except for
+// the pair's larger half; every other entry is above 1010 and can't complete a sum.
+fn expense_report(rng: &mut Rng, size: usize) -> Result<String, String> {
+    at_least(1, size, 5, "entries")?;
+
+    let sums = |entries: &[i64]| {
+        let pairs = entries.iter().flat_map(|x| entries.iter().map(move |y| x + y));
+        let mut triples = Vec::new();
+        for x in entries {
+            for y in entries {
+                for z in entries {
+                    triples.push(x + y + z);
+                }
+            }
+        }
+        (pairs.filter(|s| *s == 2020).count(), triples.iter().filter(|s| **s == 2020).count())
+    };
+
+    let special = loop {
+        let a = rng.range(1, 1009);
+        let b = rng.range(2, 1009);
+        let c = rng.range((1011 - b).max(1), 1009.min(2019 - b));
+        let special = vec![a, 2020 - a, b, c, 2020 - b - c];
+        let distinct: HashSet<&i64> = special.iter().collect();
+        // One pair counted both ways round, one triple in its six orders
+        if distinct.len() == 5 && sums(&special) == (2, 6) {
+            break special;
+        }
+    };
+
+    let mut excluded: HashSet<i64> = special.iter().map(|s| 2020 - s).collect();
+    for s in &special {
+        for t in &special {
+            excluded.insert(2020 - s - t);
+        }
+    }
+
+    let high = 2019.max(1011 + 2 * size as i64);
+    let mut entries: HashSet<i64> = special.iter().copied().collect();
+    while entries.len() < size {
+        let entry = rng.range(1011, high);
+        if !excluded.contains(&entry) {
+            entries.insert(entry);
+        }
+    }
+
+    let mut entries: Vec<i64> = entries.into_iter().collect();
+    entries.sort_unstable();
+    rng.shuffle(&mut entries);
+    Ok(lines(&entries))
+}
+
+// size passwords
+fn password_database(rng: &mut Rng, size: usize) -> Result<String, String> {
+    let entries: Vec<String> = (0..size).map(|_| {
+        let letter = rng.letter();
+        let first = rng.range(1, 8);
+        let second = rng.range(first + 1, first + 10);
+        let length = rng.range(second, second + 6);
+        let password: String = (0..length)
+            .map(|_| if rng.one_in(3) { letter } else { rng.letter() })
+            .collect();
+        format!("{}-{} {}: {}", first, second, letter, password)
+    }).collect();
+
+    Ok(lines(&entries))
+}
+
+// size rows of the usual width. Part 2 multiplies five counts of trees, so on big
+// maps the trees thin out to keep the product within 64 bits.
+fn toboggan_map(rng: &mut Rng, size: usize) -> Result<String, String> {
+    at_least(3, size, 1, "row")?;
+
+    let one_in = 5.max(size as u64 / 5000);
+    let rows: Vec<String> = (0..size)
+        .map(|_| (0..31).map(|_| if rng.one_in(one_in) { '#' } else { '.' }).collect())
+        .collect();
+
+    Ok(lines(&rows))
+}
+
+// size passports, about half of them valid
+fn passport_batch(rng: &mut Rng, size: usize) -> Result<String, String> {
+    const EYE_COLOURS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+    let hex = |rng: &mut Rng| format!("{:06x}", rng.range(0, 0xff_ffff));
+
+    let passports: Vec<String> = (0..size).map(|_| {
+        let valid = !rng.one_in(2);
+        let bad = |rng: &mut Rng| !valid && rng.one_in(4);
+
+        let mut fields = vec![
+            format!("byr:{}", if bad(rng) { rng.range(1900, 1919) } else { rng.range(1920, 2002) }),
+            format!("iyr:{}", if bad(rng) { rng.range(2021, 2030) } else { rng.range(2010, 2020) }),
+            format!("eyr:{}", if bad(rng) { rng.range(2000, 2019) } else { rng.range(2020, 2030) }),
+            if bad(rng) {
+                format!("hgt:{}", rng.range(50, 200))
+            } else if rng.one_in(2) {
+                format!("hgt:{}cm", rng.range(150, 193))
+            } else {
+                format!("hgt:{}in", rng.range(59, 76))
+            },
+            format!("hcl:{}{}", if bad(rng) { "" } else { "#" }, hex(rng)),
+            format!("ecl:{}", if bad(rng) { "xry".to_string() } else { rng.pick(&EYE_COLOURS).to_string() }),
+            format!("pid:{:0width$}", rng.range(0, 999_999_999), width = if bad(rng) { 10 } else { 9 }),
+        ];
+        if rng.one_in(2) {
+            fields.push(format!("cid:{}", rng.range(50, 350)));
+        }
+        if !valid && rng.one_in(3) {
+            fields.remove(rng.below(7));
+        }
+        rng.shuffle(&mut fields);
+
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| if i == 0 { field.to_string() } else if rng.one_in(3) { "\n".to_string() + field } else { " ".to_string() + field })
+            .collect::<String>()
+    }).collect();
+
+    Ok(passports.join("\n\n") + "\n")
+}
+
+// size boarding passes: a block of seats with one missing from the middle
+fn boarding_passes(rng: &mut Rng, size: usize) -> Result<String, String> {
+    at_least(5, size, 2, "boarding passes")?;
+    at_most(5, size, 1022, "boarding passes, as the plane has 1024 seats")?;
+
+    let start = rng.range(0, 1023 - size as i64);
+    let missing = start + rng.range(1, size as i64 - 1);
+
+    let mut passes: Vec<String> = (start..=start + size as i64)
+        .filter(|id| *id != missing)
+        .map(|id| (0..10)
+            .map(|bit| match (bit < 7, id & (1 << (9 - bit)) != 0) {
+                (true, false) => 'F',
+                (true, true) => 'B',
+                (false, false) => 'L',
+                (false, true) => 'R'
+            })
+            .collect())
+        .collect();
+
+    rng.shuffle(&mut passes);
+    Ok(lines(&passes))
+}
+
+// size groups of one to five people
+fn customs_forms(rng: &mut Rng, size: usize) -> Result<String, String> {
+    let groups: Vec<String> = (0..size).map(|_| {
+        let common: Vec<char> = ('a'..='z').filter(|_| rng.one_in(3)).collect();
+        let people: Vec<String> = (0..rng.range(1, 5)).map(|_| {
+            let mut answers: String = ('a'..='z')
+                .filter(|c| if common.contains(c) { !rng.one_in(5) } else { rng.one_in(10) })
+                .collect();
+            if answers.is_empty() {
+                answers.push(rng.letter());
+            }
+            answers
+        }).collect();
+        people.join("\n")
+    }).collect();
+
+    Ok(groups.join("\n\n") + "\n")
+}
+
+// size bags. The bags are in a few levels, each only holding bags from the next, so
+// there are no cycles and the number of bags inside shiny gold stays countable.
+fn bag_rules(rng: &mut Rng, size: usize) -> Result<String, String> {
+    const LEVELS: usize = 6;
+    const ADJECTIVES: [&str; 20] = [
+        "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+        "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dusky", "mottled"
+    ];
+    const COLOURS: [&str; 30] = [
+        "aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral", "crimson", "cyan",
+        "fuchsia", "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive",
+        "orange", "plum", "purple", "red", "salmon", "silver", "tan", "teal", "tomato", "turquoise"
+    ];
+
+    at_least(7, size, LEVELS, "bags")?;
+
+    // Past every adjective and colour pair the adjectives are numbered
+    let mut names: Vec<String> = (0..)
+        .map(|i| {
+            let round = i / (ADJECTIVES.len() * COLOURS.len());
+            let adjective = ADJECTIVES[i % ADJECTIVES.len()];
+            let colour = COLOURS[(i / ADJECTIVES.len()) % COLOURS.len()];
+            match round {
+                0 => format!("{} {}", adjective, colour),
+                _ => format!("{}{} {}", adjective, round, colour)
+            }
+        })
+        .filter(|name| name != "shiny gold")
+        .take(size - 1)
+        .collect();
+    rng.shuffle(&mut names);
+
+    let level_of = |i: usize| i * LEVELS / size;
+    let gold = (size * 2 / LEVELS..size).find(|i| level_of(*i) == 2).unwrap();
+    names.insert(gold, "shiny gold".to_string());
+
+    let mut contents: Vec<Vec<(usize, i64)>> = vec![Vec::new(); size];
+    for (i, bags) in contents.iter_mut().enumerate() {
+        let next: Vec<usize> = (i..size).filter(|j| level_of(*j) == level_of(i) + 1).collect();
+        if next.is_empty() || (i != gold && rng.one_in(8)) {
+            continue;
+        }
+        for _ in 0..rng.range(1, 4) {
+            let bag = *rng.pick(&next);
+            if bags.iter().all(|(b, _)| *b != bag) {
+                bags.push((bag, rng.range(1, 5)));
+            }
+        }
+    }
+
+    // Make sure something holds shiny gold, for part 1
+    if !contents.iter().any(|bags| bags.iter().any(|(b, _)| *b == gold)) {
+        let holder = (0..size).find(|i| level_of(*i) == 1).unwrap();
+        contents[holder].push((gold, rng.range(1, 5)));
+    }
+
+    let mut rules: Vec<String> = contents
+        .iter()
+        .enumerate()
+        .map(|(i, bags)| {
+            let held = if bags.is_empty() {
+                "no other bags".to_string()
+            } else {
+                bags.iter()
+                    .map(|(b, n)| format!("{} {} {}", n, names[*b], if *n == 1 { "bag" } else { "bags" }))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            format!("{} bags contain {}.", names[i], held)
+        })
+        .collect();
+
+    rng.shuffle(&mut rules);
+    Ok(lines(&rules))
+}
+
+// size instructions. The first half is run in a shuffled order linked by jumps and
+// ends with a jump back to the start; the second half runs straight to the end. So
+// the only jmp whose change to nop lets the program finish is that last one.
+fn boot_code(rng: &mut Rng, size: usize) -> Result<String, String> {
+    at_least(8, size, 4, "instructions")?;
+
+    let half = size / 2;
+
+    let mut segments: Vec<Vec<usize>> = Vec::new();
+    let mut next = 1;
+    while next < half - 1 {
+        let end = (next + rng.range(1, 8) as usize).min(half - 1);
+        segments.push((next..end).collect());
+        next = end;
+    }
+    rng.shuffle(&mut segments);
+
+    let mut path = vec![0];
+    path.extend(segments.into_iter().flatten());
+    path.push(half - 1);
+
+    let straight = |rng: &mut Rng| if rng.one_in(3) {
+        format!("nop {:+}", rng.range(-(size as i64), size as i64))
+    } else {
+        format!("acc {:+}", rng.range(-50, 50))
+    };
+
+    let mut program = vec![String::new(); size];
+    for step in path.windows(2) {
+        program[step[0]] = if step[1] == step[0] + 1 {
+            straight(rng)
+        } else {
+            format!("jmp {:+}", step[1] as i64 - step[0] as i64)
+        };
+    }
+    program[half - 1] = format!("jmp {:+}", -(half as i64 - 1));
+    for instruction in program.iter_mut().skip(half) {
+        *instruction = straight(rng);
+    }
+
+    Ok(lines(&program))
+}
+
+// size numbers, positive and increasing. Each is the sum of two of the 25 before it,
+// at most the first of them plus the last, which grows the numbers by about a tenth
+// at every step, so no more than 400 fit in 64 bits. The one that isn't a sum comes
+// three quarters of the way through and adds up the numbers just before it.
+fn xmas_data(rng: &mut Rng, size: usize) -> Result<String, String> {
+    const WINDOW: usize = 25;
+
+    at_least(9, size, WINDOW + 5, "numbers")?;
+    at_most(9, size, 400, "numbers, as they'd grow past 64 bits")?;
+
+    let mut numbers: Vec<i64> = (1..=100).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(WINDOW);
+    numbers.sort();
+
+    let anomaly_at = (size * 3 / 4).max(WINDOW + 3);
+
+    while numbers.len() < size {
+        let window = &numbers[numbers.len() - WINDOW..];
+        let last = window[WINDOW - 1];
+        let is_sum = |n: i64| window.iter().any(|a| n - a != *a && window.contains(&(n - a)));
+
+        let n = if numbers.len() == anomaly_at {
+            // The sum of the last few numbers, which is bigger than any of them, and
+            // that no two numbers in the window add up to. All of the window is always
+            // too big to be one of its sums.
+            let mut lengths: Vec<usize> = (2..WINDOW).collect();
+            rng.shuffle(&mut lengths);
+            lengths.push(WINDOW);
+            lengths
+                .iter()
+                .map(|length| window[WINDOW - length..].iter().sum())
+                .find(|n| !is_sum(*n))
+                .unwrap()
+        } else {
+            // The first number plus the last is always one of these
+            let mut sums = Vec::new();
+            for (i, a) in window.iter().enumerate() {
+                for b in &window[i + 1..] {
+                    if (last + 1..=window[0] + last).contains(&(a + b)) {
+                        sums.push(a + b);
+                    }
+                }
+            }
+            *rng.pick(&sums)
+        };
+
+        numbers.push(n);
+    }
+
+    Ok(lines(&numbers))
+}
+
+// size adaptors. Runs of adaptors one jolt apart multiply the number of arrangements,
+// so once a few dozen runs have been used the rest are kept to single steps.
+fn adaptors(rng: &mut Rng, size: usize) -> Result<String, String> {
+    // The ways through a run of n adaptors, one jolt apart with three either side
+    const ARRANGEMENTS: [u64; 6] = [1, 1, 1, 2, 4, 7];
+
+    let mut joltages = Vec::new();
+    let mut joltage = 0;
+    let mut arrangements: u64 = 1;
+
+    while joltages.len() < size {
+        let mut run = rng.range(1, 5) as usize;
+        if arrangements.checked_mul(ARRANGEMENTS[run]).is_none_or(|a| a > u64::MAX / 8) {
+            run = 1;
+        }
+        arrangements *= ARRANGEMENTS[run];
+
+        joltage += 3;
+        joltages.push(joltage);
+        for _ in 1..run {
+            joltage += 1;
+            joltages.push(joltage);
+        }
+    }
+
+    joltages.truncate(size);
+    rng.shuffle(&mut joltages);
+    Ok(lines(&joltages))
+}
+
+// A size by size layout. Plenty of random layouts never settle, with blocks of seats
+// filling and emptying in turn, so those seats are made floor until the layout
+// settles under both rules.
+fn seat_layout(rng: &mut Rng, size: usize) -> Result<String, String> {
+    at_least(11, size, 1, "row")?;
+
+    let mut layout = Grid::from_fn(size, size, |_, _| if rng.one_in(8) { '.' } else { 'L' });
+
+    loop {
+        let mut restless = restless_seats(&layout, false);
+        restless.extend(restless_seats(&layout, true));
+        if restless.is_empty() {
+            break;
+        }
+        for p in restless {
+            layout[p] = '.';
+        }
+    }
+
+    Ok(layout.to_string())
+}
+
+// The seats still changing once the layout should have settled, under the adjacent
+// seats rule of part 1 or the visible seats rule of part 2. Every seat fills in the
+// first round, and after that most fill and empty together, full after odd rounds and
+// empty after even ones, until the seats around them settle; that can take as many
+// rounds as the layout is wide. So only the seats that have fallen out of step with
+// the rest, and those next to them, are looked at each round.
+fn restless_seats(layout: &Grid<char>, visible: bool) -> Vec<(usize, usize)> {
+    let crowded = if visible { 5 } else { 4 };
+    let width = layout.width();
+    let index = |(x, y): (usize, usize)| y * width + x;
+
+    // A seat sees another just when the other sees it back, so these are also the
+    // seats that take notice of it
+    let neighbours: Vec<Vec<usize>> = layout
+        .positions()
+        .map(|(x, y)| match layout[(x, y)] {
+            '.' => Vec::new(),
+            _ => ALL_DIRECTIONS
+                .iter()
+                .filter_map(|d| {
+                    let mut ray = layout.ray(x, y, *d);
+                    let seat = if visible { ray.find(|p| layout[*p] != '.') } else { ray.next() };
+                    seat.filter(|p| layout[*p] != '.').map(index)
+                })
+                .collect()
+        })
+        .collect();
+
+    let seats: Vec<usize> = layout.positions().filter(|p| layout[*p] != '.').map(index).collect();
+    let mut in_step: Vec<bool> = layout.iter().map(|c| *c != '.').collect();
+    // Only kept for the seats out of step
+    let mut occupied = vec![false; in_step.len()];
+    let full = |in_step: &[bool], occupied: &[bool], i: usize, round: usize| {
+        if in_step[i] { round % 2 == 1 } else { occupied[i] }
+    };
+
+    // Each seat once, however many times it comes up
+    let mut seen = vec![false; in_step.len()];
+    let mut gather = |seats: &mut dyn Iterator<Item = usize>| {
+        let gathered: Vec<usize> = seats.filter(|i| !std::mem::replace(&mut seen[*i], true)).collect();
+        for i in gathered.iter() {
+            seen[*i] = false;
+        }
+        gathered
+    };
+
+    // The second round is the first in which a seat can fall out of step
+    let mut looking_at = seats.clone();
+    let mut border: Vec<usize> = Vec::new();
+    let mut last_flips = Vec::new();
+
+    for round in 2..seats.len() + 100 {
+        let mut flips = Vec::new();
+        let mut fallen_out = Vec::new();
+        for i in looking_at.iter().copied() {
+            let was = full(&in_step, &occupied, i, round - 1);
+            let around = neighbours[i].iter().filter(|j| full(&in_step, &occupied, **j, round - 1)).count();
+            let now = if was { around < crowded } else { around == 0 };
+            if in_step[i] && now == was {
+                fallen_out.push(i);
+            } else if !in_step[i] && now != was {
+                flips.push(i);
+            }
+        }
+
+        for i in fallen_out.iter().copied() {
+            occupied[i] = full(&in_step, &occupied, i, round - 1);
+            in_step[i] = false;
+        }
+        for i in flips.iter().copied() {
+            occupied[i] = !occupied[i];
+        }
+
+        // With the same seats flipping as last round, and the seats in step flipping
+        // every round, the layout is back where it was two rounds ago and will flip
+        // back and forth for ever
+        flips.sort_unstable();
+        if fallen_out.is_empty() && flips == last_flips {
+            last_flips = flips;
+            break;
+        }
+
+        // The seats next to one of the other kind, in step or not
+        border = gather(&mut border
+            .iter()
+            .chain(fallen_out.iter())
+            .chain(fallen_out.iter().flat_map(|i| neighbours[*i].iter()))
+            .copied()
+            .filter(|i| neighbours[*i].iter().any(|j| in_step[*j] != in_step[*i])));
+        looking_at = gather(&mut border
+            .iter()
+            .chain(flips.iter())
+            .chain(flips.iter().flat_map(|i| neighbours[*i].iter()))
+            .copied());
+        last_flips = flips;
+    }
+
+    seats
+        .into_iter()
+        .filter(|i| in_step[*i] || last_flips.binary_search(i).is_ok())
+        .map(|i| (i % width, i / width))
+        .collect()
+}
+
+// size instructions. The waypoint is steered back whenever it wanders far away so
+// neither the ship nor the waypoint can run out of range.
+fn navigation_instructions(rng: &mut Rng, size: usize) -> Result<String, String> {
+    let mut waypoint = (10, 1);
+
+    let instructions: Vec<String> = (0..size).map(|_| match rng.range(0, 9) {
+        0..=3 => {
+            let (x, y) = waypoint;
+            let action = match rng.range(0, 3) {
+                _ if x > 50 => 'W',
+                _ if x < -50 => 'E',
+                _ if y > 50 => 'S',
+                _ if y < -50 => 'N',
+                0 => 'N',
+                1 => 'E',
+                2 => 'S',
+                _ => 'W'
+            };
+            let n = rng.range(1, 5);
+            waypoint = match action {
+                'N' => (x, y + n),
+                'E' => (x + n, y),
+                'S' => (x, y - n),
+                _ => (x - n, y)
+            };
+            format!("{}{}", action, n)
+        },
+        4..=5 => {
+            let (action, degrees) = (*rng.pick(&['L', 'R']), 90 * rng.range(1, 3));
+            for _ in 0..degrees / 90 {
+                let (x, y) = waypoint;
+                waypoint = if action == 'L' { (-y, x) } else { (y, -x) };
+            }
+            format!("{}{}", action, degrees)
+        },
+        _ => format!("F{}", rng.range(1, 20))
+    }).collect();
+
+    Ok(lines(&instructions))
+}
+
+// size bus slots. The ids are distinct primes, so a timestamp always exists, and there
+// are only as many buses as keep the timestamp within 64 bits.
+fn bus_notes(rng: &mut Rng, size: usize) -> Result<String, String> {
+    at_least(13, size, 1, "bus")?;
+
+    let mut primes: Vec<u64> = (11..1000).filter(|n| (2..*n).take_while(|d| d * d <= *n).all(|d| n % d != 0)).collect();
+    rng.shuffle(&mut primes);
+
+    let mut slots = vec!["x".to_string(); size];
+    let mut positions: Vec<usize> = (1..size).collect();
+    rng.shuffle(&mut positions);
+    positions.insert(0, 0);
+
+    let mut product: u64 = 1;
+    for (position, id) in positions.iter().zip(primes) {
+        match product.checked_mul(id) {
+            Some(p) if p < 1 << 50 => product = p,
+            _ => break
+        }
+        slots[*position] = id.to_string();
+    }
+
+    Ok(format!("{}\n{}\n", rng.range(100_000, 10_000_000), slots.join(",")))
+}
+
+// size lines. Masks have at most nine floating bits, so each write in part 2 touches
+// at most 512 addresses.
+fn docking_program(rng: &mut Rng, size: usize) -> Result<String, String> {
+    let program: Vec<String> = (0..size).map(|i| {
+        if i == 0 || rng.one_in(5) {
+            let mut mask: Vec<char> = (0..36).map(|_| if rng.one_in(2) { '1' } else { '0' }).collect();
+            for _ in 0..rng.range(0, 9) {
+                mask[rng.below(36)] = 'X';
+            }
+            format!("mask = {}", mask.into_iter().collect::<String>())
+        } else {
+            format!("mem[{}] = {}", rng.range(0, 65535), rng.range(0, 1 << 30))
+        }
+    }).collect();
+
+    Ok(lines(&program))
+}
+
+// size distinct starting numbers
+fn starting_numbers(rng: &mut Rng, size: usize) -> Result<String, String> {
+    at_least(15, size, 1, "starting number")?;
+
+    let mut numbers: Vec<usize> = (0..size * 2).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(size);
+
+    Ok(numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(",") + "\n")
+}
+
+// size nearby tickets. The value at each position is drawn from a band of numbers,
+// and the field of rank r accepts the bands up to r, so there is exactly one order of
+// fields and working it out means eliminating them one at a time.
+fn ticket_notes(rng: &mut Rng, size: usize) -> Result<String, String> {
+    const FIELDS: [&str; 20] = [
+        "departure location", "departure station", "departure platform", "departure track",
+        "departure date", "departure time", "arrival location", "arrival station",
+        "arrival platform", "arrival track", "class", "duration", "price", "route", "row",
+        "seat", "train", "type", "wagon", "zone"
+    ];
+    const BAND: i64 = 50;
+    let fields = FIELDS.len();
+    let unused = BAND * fields as i64 + 1..BAND * fields as i64 + 100;
+
+    let mut ranks: Vec<usize> = (0..fields).collect();
+    rng.shuffle(&mut ranks);
+    let mut bands: Vec<usize> = (0..fields).collect();
+    rng.shuffle(&mut bands);
+
+    let mut notes: Vec<String> = FIELDS
+        .iter()
+        .zip(&ranks)
+        .map(|(name, rank)| {
+            let top = BAND * (*rank as i64 + 1) - rng.range(0, 4);
+            let extra = unused.end + 10 * *rank as i64;
+            format!("{}: {}-{} or {}-{}", name, rng.range(1, 5), top, extra, extra + rng.range(0, 9))
+        })
+        .collect();
+
+    let ticket = |rng: &mut Rng, valid: bool| {
+        let mut values: Vec<i64> = bands
+            .iter()
+            .map(|band| BAND * *band as i64 + rng.range(6, BAND - 5))
+            .collect();
+        if !valid {
+            values[rng.below(fields)] = rng.range(unused.start, unused.end - 1);
+        }
+        values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+    };
+
+    notes.push(String::new());
+    notes.push("your ticket:".to_string());
+    notes.push(ticket(rng, true));
+    notes.push(String::new());
+    notes.push("nearby tickets:".to_string());
+    for _ in 0..size {
+        let valid = !rng.one_in(4);
+        notes.push(ticket(rng, valid));
+    }
+
+    Ok(lines(&notes))
+}
+
+// A size by size starting region
+fn conway_cubes(rng: &mut Rng, size: usize) -> Result<String, String> {
+    at_least(17, size, 1, "cube")?;
+
+    let region = Grid::from_fn(size, size, |_, _| if rng.one_in(2) { '#' } else { '.' });
+    Ok(region.to_string())
+}
+
+// size expressions of up to twelve numbers, which keeps every sum within 64 bits
+fn homework(rng: &mut Rng, size: usize) -> Result<String, String> {
+    fn expression(rng: &mut Rng, numbers: &mut usize, depth: usize) -> String {
+        let mut terms = Vec::new();
+        for i in 0..rng.range(2, 4) {
+            if i > 0 {
+                terms.push(rng.pick(&["+", "*"]).to_string());
+            }
+            if *numbers > 1 && depth < 3 && rng.one_in(4) {
+                terms.push(format!("({})", expression(rng, numbers, depth + 1)));
+            } else {
+                *numbers = numbers.saturating_sub(1);
+                terms.push(rng.range(1, 9).to_string());
+            }
+        }
+        terms.join(" ")
+    }
+
+    let expressions: Vec<String> = (0..size).map(|_| expression(rng, &mut 12, 0)).collect();
+    Ok(lines(&expressions))
+}
+
+// size messages. As in the real input, rule 0 is 8 11 and rules 42 and 31 each match
+// fixed length words, 42's starting with a and 31's with b so they never overlap.
+fn messages(rng: &mut Rng, size: usize) -> Result<String, String> {
+    enum GenRule {
+        Letter(char),
+        Either(Vec<usize>, Vec<usize>)
+    }
+
+    struct Grammar {
+        rules: Vec<(usize, GenRule)>,
+        next: usize
+    }
+
+    impl Grammar {
+        fn add(&mut self, number: Option<usize>, rule: GenRule) -> usize {
+            let number = number.unwrap_or_else(|| {
+                while [0, 8, 11, 31, 42].contains(&self.next) {
+                    self.next += 1;
+                }
+                self.next += 1;
+                self.next - 1
+            });
+            self.rules.push((number, rule));
+            number
+        }
+
+        fn random(&mut self, rng: &mut Rng, depth: usize, letters: (usize, usize)) -> usize {
+            if depth == 0 {
+                return if rng.one_in(2) { letters.0 } else { letters.1 };
+            }
+            let mut side = |rng: &mut Rng| vec![self.random(rng, depth - 1, letters), self.random(rng, depth - 1, letters)];
+            let rule = GenRule::Either(side(rng), side(rng));
+            self.add(None, rule)
+        }
+
+        fn expand(&self, rng: &mut Rng, number: usize, word: &mut String) {
+            match &self.rules.iter().find(|(n, _)| *n == number).unwrap().1 {
+                GenRule::Letter(letter) => word.push(*letter),
+                GenRule::Either(a, b) => {
+                    for n in if rng.one_in(2) { a } else { b } {
+                        self.expand(rng, *n, word);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut grammar = Grammar { rules: Vec::new(), next: 1 };
+    let letters = (grammar.add(None, GenRule::Letter('a')), grammar.add(None, GenRule::Letter('b')));
+    let tail = grammar.random(rng, 3, letters);
+    grammar.add(Some(42), GenRule::Either(vec![letters.0, tail], vec![letters.0, tail]));
+    let tail = grammar.random(rng, 3, letters);
+    grammar.add(Some(31), GenRule::Either(vec![letters.1, tail], vec![letters.1, tail]));
+
+    let message = |rng: &mut Rng, fortytwos: usize, thirtyones: usize| {
+        let mut word = String::new();
+        for _ in 0..fortytwos {
+            grammar.expand(rng, 42, &mut word);
+        }
+        for _ in 0..thirtyones {
+            grammar.expand(rng, 31, &mut word);
+        }
+        word
+    };
+
+    let messages: Vec<String> = (0..size).map(|_| match rng.range(0, 9) {
+        // Matches 0 as it is
+        0..=3 => message(rng, 2, 1),
+        // Only matches once 8 and 11 loop
+        4..=6 => {
+            let thirtyones = rng.range(1, 3) as usize;
+            let fortytwos = thirtyones + rng.range(1, 3) as usize;
+            message(rng, fortytwos + usize::from(thirtyones == 1 && fortytwos == 2), thirtyones)
+        },
+        // Never matches
+        7..=8 => {
+            let thirtyones = rng.range(1, 3) as usize;
+            let fortytwos = rng.range(0, thirtyones as i64) as usize;
+            message(rng, fortytwos, thirtyones)
+        },
+        _ => (0..rng.range(9, 40)).map(|_| if rng.one_in(2) { 'a' } else { 'b' }).collect()
+    }).collect();
+
+    let mut rules: Vec<String> = grammar
+        .rules
+        .iter()
+        .map(|(number, rule)| match rule {
+            GenRule::Letter(letter) => format!("{}: \"{}\"", number, letter),
+            GenRule::Either(a, b) if a == b => format!("{}: {} {}", number, a[0], a[1]),
+            GenRule::Either(a, b) => format!("{}: {} {} | {} {}", number, a[0], a[1], b[0], b[1])
+        })
+        .collect();
+    rules.push("0: 8 11".to_string());
+    rules.push("8: 42".to_string());
+    rules.push("11: 42 31".to_string());
+    rng.shuffle(&mut rules);
+
+    Ok(lines(&rules) + "\n" + &lines(&messages))
+}
+
+// A size by size square of tiles. Every edge is different, even flipped, so each tile
+// fits its neighbours only one way. Only so many ten pixel edges are different, which
+// limits the size to 12 tiles a side, the size of a real input. The image inside the borders is sparse, so that the sea monsters
+// put into it are the only ones there, and needs to be wide enough for one.
+fn jigsaw(rng: &mut Rng, size: usize) -> Result<String, String> {
+    const TILE: usize = 10;
+    const INNER: usize = TILE - 2;
+    const MONSTER: [&str; 3] = [
+        "                  # ",
+        "#    ##    ##    ###",
+        " #  #  #  #  #  #   "
+    ];
+
+    at_least(20, size, 3, "tiles a side")?;
+    at_most(20, size, 12, "tiles a side, as there aren't enough different edges for more")?;
+
+    // The corner pixels are shared by up to four tiles
+    let corners = Grid::from_fn(size + 1, size + 1, |_, _| rng.one_in(2));
+
+    let mut used = HashSet::new();
+    let mut edge = |rng: &mut Rng, from: bool, to: bool| loop {
+        let mut pixels = vec![from];
+        pixels.extend((1..TILE - 1).map(|_| rng.one_in(2)));
+        pixels.push(to);
+        let flipped: Vec<bool> = pixels.iter().rev().copied().collect();
+        if pixels != flipped && !used.contains(&pixels) && !used.contains(&flipped) {
+            used.insert(pixels.clone());
+            break pixels;
+        }
+    };
+
+    // Horizontal edges run left to right, vertical ones top to bottom
+    let horizontal = Grid::from_fn(size, size + 1, |x, y| edge(rng, corners[(x, y)], corners[(x + 1, y)]));
+    let vertical = Grid::from_fn(size + 1, size, |x, y| edge(rng, corners[(x, y)], corners[(x, y + 1)]));
+
+    let mut image = Grid::from_fn(size * INNER, size * INNER, |_, _| rng.one_in(4));
+    for _ in 0..size * size / 4 + 1 {
+        let x = rng.below(image.width() - MONSTER[0].len() + 1);
+        let y = rng.below(image.height() - MONSTER.len() + 1);
+        for (dy, row) in MONSTER.iter().enumerate() {
+            for (dx, _) in row.char_indices().filter(|(_, c)| *c == '#') {
+                image[(x + dx, y + dy)] = true;
+            }
+        }
+    }
+
+    let mut ids: Vec<usize> = (1000..10000).collect();
+    rng.shuffle(&mut ids);
+
+    let mut tiles: Vec<String> = Vec::new();
+    for (tx, ty) in Grid::new(size, size, ()).positions() {
+        let tile = Grid::from_fn(TILE, TILE, |x, y| match (x, y) {
+            (_, 0) => horizontal[(tx, ty)][x],
+            (_, y) if y == TILE - 1 => horizontal[(tx, ty + 1)][x],
+            (0, _) => vertical[(tx, ty)][y],
+            (x, _) if x == TILE - 1 => vertical[(tx + 1, ty)][y],
+            _ => image[(tx * INNER + x - 1, ty * INNER + y - 1)]
+        });
+        let orientation = rng.pick(&tile.orientations()).map(|p| if *p { '#' } else { '.' });
+        tiles.push(format!("Tile {}:\n{}", ids[tiles.len()], orientation));
+    }
+
+    rng.shuffle(&mut tiles);
+    Ok(tiles.join("\n"))
+}
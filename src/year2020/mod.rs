@@ -0,0 +1,55 @@
+// Advent of Code 2020
+use crate::solution::Day;
+
+pub mod examples;
+pub mod generate;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+
+pub const YEAR: u32 = 2020;
+
+// Every day of the year the runner knows about, in order. Adding a day means adding
+// one line here, which aoc new dayN does along with the module.
+pub fn registry() -> Vec<Day> {
+    vec![
+        Day::new::<day01::ExpenseReport>(YEAR, 1, "Report Repair"),
+        Day::new::<day02::PasswordDatabase>(YEAR, 2, "Password Philosophy"),
+        Day::new::<day03::TobogganMap>(YEAR, 3, "Toboggan Trajectory"),
+        Day::new::<day04::PassportBatch>(YEAR, 4, "Passport Processing"),
+        Day::new::<day05::BoardingPasses>(YEAR, 5, "Binary Boarding"),
+        Day::new::<day06::CustomsForms>(YEAR, 6, "Custom Customs"),
+        Day::new::<day07::BagGraph>(YEAR, 7, "Handy Haversacks"),
+        Day::new::<day08::BootCode>(YEAR, 8, "Handheld Halting"),
+        Day::new::<day09::XmasData>(YEAR, 9, "Encoding Error"),
+        Day::new::<day10::Adaptors>(YEAR, 10, "Adapter Array"),
+        Day::new::<day11::SeatLayout>(YEAR, 11, "Seating System"),
+        Day::new::<day12::NavigationInstructions>(YEAR, 12, "Rain Risk"),
+        Day::new::<day13::BusNotes>(YEAR, 13, "Shuttle Search"),
+        Day::new::<day14::InitializationProgram>(YEAR, 14, "Docking Data"),
+        Day::new::<day15::StartingNumbers>(YEAR, 15, "Rambunctious Recitation"),
+        Day::new::<day16::Puzzle>(YEAR, 16, "Ticket Translation"),
+        Day::new::<day17::ConwayCubes>(YEAR, 17, "Conway Cubes"),
+        Day::new::<day18::Homework>(YEAR, 18, "Operation Order"),
+        Day::new::<day19::Messages>(YEAR, 19, "Monster Messages"),
+        Day::new::<day20::Jigsaw>(YEAR, 20, "Jurassic Jigsaw"),
+    ]
+}